@@ -0,0 +1,114 @@
+//! Measures binding 1M addresses into Postgres arguments.
+//!
+//! Run with `cargo bench --bench address_bind`. Before the timed runs, one
+//! pass of each variant is made under a counting allocator, and the run fails
+//! unless every variant makes 0 heap allocations across all its binds. The
+//! argument buffer is reserved up front so only the per-bind work is counted.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use alloy_primitives::Address;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use sqlx::{Arguments, postgres::PgArguments};
+
+const BINDS: usize = 1_000_000;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn addresses() -> Vec<Address> {
+    (0..BINDS as u64)
+        .map(|i| {
+            let mut bytes = [0u8; 20];
+            bytes[12..].copy_from_slice(&i.to_be_bytes());
+            Address::from(bytes)
+        })
+        .collect()
+}
+
+fn reserved_args() -> PgArguments {
+    let mut args = PgArguments::default();
+    // 4-byte length prefix plus 20 bytes of payload per bind
+    args.reserve(BINDS, BINDS * 24);
+    args
+}
+
+fn bind_by_ref(addrs: &[Address]) -> PgArguments {
+    let mut args = reserved_args();
+    for addr in addrs {
+        args.add(addr).expect("Failed to bind address");
+    }
+    args
+}
+
+fn bind_by_value(addrs: &[Address]) -> PgArguments {
+    let mut args = reserved_args();
+    for addr in addrs {
+        args.add(*addr).expect("Failed to bind address");
+    }
+    args
+}
+
+// Baseline: the borrowed byte slice, which sqlx copies straight into the buffer
+fn bind_slice(addrs: &[Address]) -> PgArguments {
+    let mut args = reserved_args();
+    for addr in addrs {
+        args.add(addr.as_slice()).expect("Failed to bind address");
+    }
+    args
+}
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let value = f();
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(value);
+    after - before
+}
+
+fn assert_no_allocations(name: &str, addrs: &[Address], bind: fn(&[Address]) -> PgArguments) {
+    // Don't charge the up-front reservation to the binds
+    let reservation = count_allocations(reserved_args);
+    let allocs = count_allocations(|| bind(addrs)) - reservation;
+    println!("{name}: {allocs} allocations for {} binds", addrs.len());
+    assert_eq!(allocs, 0, "{name} allocated while binding");
+}
+
+fn bench_address_bind(c: &mut Criterion) {
+    let addrs = addresses();
+
+    assert_no_allocations("slice", &addrs, bind_slice);
+    assert_no_allocations("by_ref", &addrs, bind_by_ref);
+    assert_no_allocations("by_value", &addrs, bind_by_value);
+
+    let mut group = c.benchmark_group("bind_1m_addresses");
+    group.sample_size(10);
+    group.bench_function("slice", |b| b.iter(|| black_box(bind_slice(&addrs))));
+    group.bench_function("by_ref", |b| b.iter(|| black_box(bind_by_ref(&addrs))));
+    group.bench_function("by_value", |b| b.iter(|| black_box(bind_by_value(&addrs))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_address_bind);
+criterion_main!(benches);
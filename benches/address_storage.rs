@@ -0,0 +1,140 @@
+//! Compares binary and text `Address` storage on SQLite: batched inserts
+//! and indexed point lookups.
+//!
+//! Run with `cargo bench --bench address_storage`. Criterion reports each
+//! benchmark as elements per second, i.e. inserted rows or lookups per second.
+//! Absolute numbers depend on the machine, so compare the two variants
+//! within one run rather than across runs.
+
+use alloy_primitives::{Address, keccak256};
+use criterion::{BatchSize, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use sqlx::SqlitePool;
+use test_alloy_sqlx::AddressText;
+use tokio::runtime::Runtime;
+
+const TABLE_ROWS: usize = 10_000;
+const BATCH: usize = 1_000;
+
+fn addresses(n: usize, seed: u64) -> Vec<Address> {
+    (0..n as u64)
+        .map(|i| Address::from_slice(&keccak256([seed.to_be_bytes(), i.to_be_bytes()].concat())[12..]))
+        .collect()
+}
+
+async fn setup(pool: &SqlitePool) {
+    for (table, column_type) in [("binary_addresses", "BLOB"), ("text_addresses", "TEXT")] {
+        sqlx::query(&format!("DROP TABLE IF EXISTS {table}"))
+            .execute(pool)
+            .await
+            .expect("Failed to drop table");
+        sqlx::query(&format!("CREATE TABLE {table} (id INTEGER PRIMARY KEY, address {column_type} NOT NULL)"))
+            .execute(pool)
+            .await
+            .expect("Failed to create table");
+        sqlx::query(&format!("CREATE INDEX idx_{table} ON {table} (address)"))
+            .execute(pool)
+            .await
+            .expect("Failed to create index");
+    }
+}
+
+async fn insert_binary(pool: &SqlitePool, addrs: &[Address]) {
+    let mut tx = pool.begin().await.expect("Failed to begin transaction");
+    for addr in addrs {
+        sqlx::query("INSERT INTO binary_addresses (address) VALUES (?)")
+            .bind(addr)
+            .execute(&mut *tx)
+            .await
+            .expect("Failed to insert address");
+    }
+    tx.commit().await.expect("Failed to commit transaction");
+}
+
+async fn insert_text(pool: &SqlitePool, addrs: &[Address]) {
+    let mut tx = pool.begin().await.expect("Failed to begin transaction");
+    for addr in addrs {
+        sqlx::query("INSERT INTO text_addresses (address) VALUES (?)")
+            .bind(AddressText(*addr))
+            .execute(&mut *tx)
+            .await
+            .expect("Failed to insert address");
+    }
+    tx.commit().await.expect("Failed to commit transaction");
+}
+
+async fn lookup_binary(pool: &SqlitePool, addrs: &[Address]) {
+    for addr in addrs {
+        let id: i64 = sqlx::query_scalar("SELECT id FROM binary_addresses WHERE address = ?")
+            .bind(addr)
+            .fetch_one(pool)
+            .await
+            .expect("Failed to look up address");
+        black_box(id);
+    }
+}
+
+async fn lookup_text(pool: &SqlitePool, addrs: &[Address]) {
+    for addr in addrs {
+        let id: i64 = sqlx::query_scalar("SELECT id FROM text_addresses WHERE address = ?")
+            .bind(AddressText(*addr))
+            .fetch_one(pool)
+            .await
+            .expect("Failed to look up address");
+        black_box(id);
+    }
+}
+
+fn bench_address_storage(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("Failed to start runtime");
+    // One connection, so every query sees the same in-memory database
+    let pool = runtime.block_on(async {
+        sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("Failed to connect to SQLite")
+    });
+    runtime.block_on(setup(&pool));
+
+    let mut group = c.benchmark_group("insert_1k_addresses");
+    group.throughput(Throughput::Elements(BATCH as u64));
+    // Fresh addresses per batch, generated outside the timed routine
+    let mut seed = 0;
+    let mut next_batch = || {
+        seed += 1;
+        addresses(BATCH, seed)
+    };
+    group.bench_function("binary", |b| {
+        b.iter_batched(
+            &mut next_batch,
+            |addrs| runtime.block_on(insert_binary(&pool, &addrs)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("text", |b| {
+        b.iter_batched(
+            &mut next_batch,
+            |addrs| runtime.block_on(insert_text(&pool, &addrs)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+
+    // Fresh tables of the same size for the lookups
+    let stored = addresses(TABLE_ROWS, u64::MAX);
+    runtime.block_on(async {
+        setup(&pool).await;
+        insert_binary(&pool, &stored).await;
+        insert_text(&pool, &stored).await;
+    });
+    let probes: Vec<Address> = stored.iter().step_by(TABLE_ROWS / BATCH).copied().collect();
+
+    let mut group = c.benchmark_group("lookup_1k_of_10k_addresses");
+    group.throughput(Throughput::Elements(probes.len() as u64));
+    group.bench_function("binary", |b| b.iter(|| runtime.block_on(lookup_binary(&pool, &probes))));
+    group.bench_function("text", |b| b.iter(|| runtime.block_on(lookup_text(&pool, &probes))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_address_storage);
+criterion_main!(benches);
@@ -0,0 +1,510 @@
+//! Address codecs that complement the binary `Address` impls of alloy-primitives.
+//!
+//! # Storage choice
+//!
+//! Binding an `Address` directly uses its 20-byte binary form: `BYTEA` on
+//! Postgres, `BINARY(20)` on MySQL, `BLOB` on SQLite. That is the compact,
+//! index-friendly default. Wrap it in [`AddressText`] to target a
+//! `VARCHAR(42)` column instead; the choice is per bind site, not global.
+//!
+//! A bare `[u8; 20]` has no sqlx impls and doesn't compile as a bind; convert
+//! it with `Address::from` or bind a `FixedBytes<20>`.
+//!
+//! ```compile_fail
+//! use sqlx::Sqlite;
+//!
+//! let raw = [0u8; 20];
+//! let _ = sqlx::query::<Sqlite>("INSERT INTO t (address) VALUES (?)").bind(raw);
+//! ```
+//!
+//! SQLite only enforces storage classes, not declared types: `BLOB` and
+//! `BINARY(20)` columns both hold the raw 20-byte blob. An `Address` and a
+//! `FixedBytes<20>` with the same bytes encode identically, so either can be
+//! bound against the other's column.
+//!
+//! Binding `&Address` goes through sqlx's blanket `Encode` impl for
+//! references, which calls `encode_by_ref` on the borrowed value: the address
+//! is not cloned per bind. With the argument buffer reserved, a bind makes 0
+//! heap allocations, the same as a plain `&[u8]`; `benches/address_bind.rs`
+//! counts them and asserts that before timing anything. Owned values bind
+//! just as well, which is handy when an address is moved into a spawned task;
+//! the same holds for `B256` and `U256`.
+//!
+//! # Postgres parameter types
+//!
+//! Each Rust type binds with exactly one Postgres type: `Address`, `&Address`,
+//! [`BindHex`] and [`FlexAddress`] all report `BYTEA`, [`AddressText`] and
+//! [`ChecksumAddress`] report `TEXT`, and [`BitAddress`] reports `BIT`.
+//! Prepared statements are cached per SQL string and parameter types, so pick
+//! one wrapper per query and stick to it; mixing text and binary binds of the
+//! same SQL prepares it twice.
+//!
+//! # Ordering
+//!
+//! `ORDER BY` and range predicates agree with `Address`'s `Ord` only for
+//! sortable encodings: the big-endian binary form of alloy-primitives, or
+//! [`AddressText`], which always writes lowercase. Mixed-case (checksummed)
+//! text does not sort like the underlying bytes. Text columns on Postgres
+//! also need a byte-wise collation such as `COLLATE "C"`.
+//!
+//! # Postgres `bytea_output`
+//!
+//! Prepared queries receive `BYTEA` in binary format, which is independent of
+//! server settings. Simple-protocol queries (`sqlx::raw_sql`) receive text,
+//! and the binary `Address` decode only understands the `\x...` hex form, so
+//! those paths require `bytea_output = hex` (the Postgres default). Under
+//! `bytea_output = escape` decoding fails with an error rather than returning
+//! wrong bytes.
+
+use std::fmt;
+
+use alloy_primitives::{Address, hex};
+use sqlx::{
+    Database, Decode, Encode, Postgres, Type, TypeInfo, ValueRef,
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef},
+    types::Json,
+};
+
+/// Strips a `0x` or `0X` prefix, if present.
+pub(crate) fn strip_hex_prefix(s: &str) -> &str {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s)
+}
+
+/// Parses an address stored as text.
+///
+/// Accepts the canonical 42-char `0x`-prefixed hex form (also with the `0X`
+/// prefix some tools emit) as well as the bare 40-char form found in legacy
+/// `CHAR(40)` columns. Surrounding whitespace, such as the padding of a
+/// fixed-width `CHAR(42)` column, is ignored. Checksums are not validated.
+pub(crate) fn parse_address_str(s: &str) -> Result<Address, BoxDynError> {
+    let digits = strip_hex_prefix(s.trim());
+    if digits.len() != 40 {
+        return Err(format!("invalid address text {s:?}: expected 40 hex digits").into());
+    }
+    let mut bytes = [0u8; 20];
+    hex::decode_to_slice(digits, &mut bytes)?;
+    Ok(Address::from(bytes))
+}
+
+/// Parses an address stored as text, validating mixed-case input as an
+/// EIP-55 checksum.
+///
+/// All-lowercase and all-uppercase digits carry no checksum and are accepted
+/// as-is. Mixed case that doesn't match the checksum points to corrupted data
+/// and is rejected.
+pub(crate) fn parse_address_str_strict(s: &str) -> Result<Address, BoxDynError> {
+    let address = parse_address_str(s)?;
+    let digits = strip_hex_prefix(s.trim());
+    let has_lower = digits.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = digits.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper && digits != &address.to_checksum(None)[2..] {
+        return Err(format!("invalid address text {s:?}: bad EIP-55 checksum").into());
+    }
+    Ok(address)
+}
+
+/// Parses a binary column that holds either the raw 20 address bytes or the
+/// ASCII text of an address, as left behind by `ALTER COLUMN ... TYPE bytea
+/// USING col::bytea`. Hex text is 40 or more bytes long, so the two forms
+/// can't be confused.
+pub(crate) fn parse_address_bytes(bytes: &[u8]) -> Result<Address, BoxDynError> {
+    if bytes.len() == 20 {
+        return Ok(Address::from_slice(bytes));
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => parse_address_str(text),
+        Err(_) => Err(format!(
+            "invalid address value: expected 20 bytes or hex text, got {} bytes",
+            bytes.len()
+        )
+        .into()),
+    }
+}
+
+/// Why [`bind_validated`] rejected its input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvalidAddress {
+    /// The input contains characters other than hex digits after the prefix.
+    NonHex(String),
+    /// The input doesn't have exactly 40 hex digits.
+    Length(String),
+    /// Mixed-case input that doesn't match its EIP-55 checksum.
+    Checksum(String),
+}
+
+impl fmt::Display for InvalidAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonHex(input) => write!(f, "invalid address {input:?}: not hex"),
+            Self::Length(input) => write!(f, "invalid address {input:?}: expected 40 hex digits"),
+            Self::Checksum(input) => write!(f, "invalid address {input:?}: bad EIP-55 checksum"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidAddress {}
+
+/// Validates untrusted address input, e.g. from an API request, and returns
+/// the [`Address`] to bind.
+///
+/// Accepts the `0x`/`0X` prefix or bare hex, with no surrounding whitespace.
+/// With `verify_checksum`, mixed-case input must carry a valid EIP-55
+/// checksum; all-lowercase and all-uppercase input carries none and is
+/// accepted either way.
+pub fn bind_validated(addr: &str, verify_checksum: bool) -> Result<Address, InvalidAddress> {
+    let digits = strip_hex_prefix(addr);
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(InvalidAddress::NonHex(addr.to_string()));
+    }
+    if digits.len() != 40 {
+        return Err(InvalidAddress::Length(addr.to_string()));
+    }
+    let parse = if verify_checksum { parse_address_str_strict } else { parse_address_str };
+    parse(digits).map_err(|_| InvalidAddress::Checksum(addr.to_string()))
+}
+
+/// Splits an address into the `BIGINT` prefix and 12-byte suffix used by
+/// warehouse schemas that store it across two columns.
+///
+/// The prefix holds the first 8 bytes, big-endian, reinterpreted as a signed
+/// `i64`; [`join_address`] reverses the split.
+pub fn split_address(address: Address) -> (i64, [u8; 12]) {
+    let (prefix, suffix) = address.0.0.split_at(8);
+    let prefix = i64::from_be_bytes(prefix.try_into().expect("8-byte prefix"));
+    (prefix, suffix.try_into().expect("12-byte suffix"))
+}
+
+/// Rebuilds an address from a [`split_address`] prefix and suffix, failing if
+/// the suffix isn't exactly 12 bytes.
+pub fn join_address(prefix: i64, suffix: &[u8]) -> Result<Address, BoxDynError> {
+    if suffix.len() != 12 {
+        return Err(format!(
+            "invalid address suffix length: expected 12 bytes, got {}",
+            suffix.len()
+        )
+        .into());
+    }
+    let mut bytes = [0u8; 20];
+    bytes[..8].copy_from_slice(&prefix.to_be_bytes());
+    bytes[8..].copy_from_slice(suffix);
+    Ok(Address::from(bytes))
+}
+
+/// An [`Address`] stored as text, for `VARCHAR(42)`/`TEXT` columns.
+///
+/// Encodes the lowercase `0x`-prefixed form, so values written through this
+/// type sort in address order. Decodes prefixed or bare hex in any case,
+/// so `CHAR(42)` columns padded with spaces read back fine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AddressText(pub Address);
+
+impl From<Address> for AddressText {
+    fn from(address: Address) -> Self {
+        Self(address)
+    }
+}
+
+impl From<AddressText> for Address {
+    fn from(address: AddressText) -> Self {
+        address.0
+    }
+}
+
+impl<DB: Database> Type<DB> for AddressText
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for AddressText
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let text = hex::encode_prefixed(self.0);
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("text", text.len());
+        <String as Encode<'q, DB>>::encode(text, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for AddressText
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let text = <&'r str as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, text.len());
+        parse_address_str(text).map(Self)
+    }
+}
+
+/// An [`Address`] stored as EIP-55 checksummed text.
+///
+/// Encodes the checksummed `0x`-prefixed form. Decoding is strict: mixed-case
+/// text must carry a valid checksum, while all-lowercase and all-uppercase
+/// text is accepted. Checksummed text does not sort in address order; see
+/// the module docs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChecksumAddress(pub Address);
+
+impl From<Address> for ChecksumAddress {
+    fn from(address: Address) -> Self {
+        Self(address)
+    }
+}
+
+impl From<ChecksumAddress> for Address {
+    fn from(address: ChecksumAddress) -> Self {
+        address.0
+    }
+}
+
+impl<DB: Database> Type<DB> for ChecksumAddress
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for ChecksumAddress
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let text = self.0.to_checksum(None);
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("text", text.len());
+        <String as Encode<'q, DB>>::encode(text, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for ChecksumAddress
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let text = <&'r str as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, text.len());
+        parse_address_str_strict(text).map(Self)
+    }
+}
+
+/// Hex address text bound as a binary [`Address`].
+///
+/// Parsing happens when the argument is encoded, so glue code can bind
+/// strings from an API without an intermediate `Address`. Invalid hex fails
+/// the query with [`sqlx::Error::Encode`]. Encode-only: decode into
+/// [`Address`] or [`AddressText`] instead.
+///
+/// ```compile_fail
+/// use sqlx::Row;
+/// use test_alloy_sqlx::BindHex;
+///
+/// fn decode(row: &sqlx::sqlite::SqliteRow) {
+///     let _: BindHex<'_> = row.get("address");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BindHex<'a>(pub &'a str);
+
+impl<DB: Database> Type<DB> for BindHex<'_>
+where
+    Address: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Address as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Address as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for BindHex<'_>
+where
+    Address: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let address = parse_address_str(self.0)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", address.len());
+        <Address as Encode<'q, DB>>::encode(address, buf)
+    }
+}
+
+/// An [`Address`] that decodes from a `BYTEA`, text or JSON column on Postgres.
+///
+/// Tables half-way through a text to binary migration can be read with a
+/// single code path: text and JSON columns are parsed as hex, and `BYTEA`
+/// values are told apart by their content, so a column cast from `VARCHAR`
+/// to `BYTEA` in place reads the same whether a row still holds the address
+/// text or already holds the 20 raw bytes. `json` and `jsonb` values must be
+/// a bare hex string scalar (`"0x..."`). The binary `Address` decode lives in
+/// alloy-primitives and only reads 20-byte `BYTEA`s; use this type for
+/// everything else. Always encodes as `BYTEA`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FlexAddress(pub Address);
+
+impl From<Address> for FlexAddress {
+    fn from(address: Address) -> Self {
+        Self(address)
+    }
+}
+
+impl From<FlexAddress> for Address {
+    fn from(address: FlexAddress) -> Self {
+        address.0
+    }
+}
+
+impl Type<Postgres> for FlexAddress {
+    fn type_info() -> PgTypeInfo {
+        <Vec<u8> as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <Vec<u8> as Type<Postgres>>::compatible(ty)
+            || <String as Type<Postgres>>::compatible(ty)
+            || <Json<String> as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for FlexAddress {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", self.0.len());
+        <Address as Encode<'_, Postgres>>::encode_by_ref(&self.0, buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for FlexAddress {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        if <String as Type<Postgres>>::compatible(&value.type_info()) {
+            let text = <&str as Decode<'r, Postgres>>::decode(value)?;
+            #[cfg(feature = "trace-codec")]
+            crate::trace::decoded::<Self>(&column_type, text.len());
+            parse_address_str(text).map(Self)
+        } else if <Json<String> as Type<Postgres>>::compatible(&value.type_info()) {
+            let Json(text) = <Json<String> as Decode<'r, Postgres>>::decode(value)?;
+            #[cfg(feature = "trace-codec")]
+            crate::trace::decoded::<Self>(&column_type, text.len());
+            parse_address_str(&text).map(Self)
+        } else {
+            let bytes = <Vec<u8> as Decode<'r, Postgres>>::decode(value)?;
+            #[cfg(feature = "trace-codec")]
+            crate::trace::decoded::<Self>(&column_type, bytes.len());
+            parse_address_bytes(&bytes).map(Self)
+        }
+    }
+}
+
+/// Number of bits in an address, the width of a `bit(160)` column.
+const ADDRESS_BITS: usize = 160;
+
+/// An [`Address`] stored as a Postgres `bit(160)` or `varbit` string.
+///
+/// Bit-packed schemas keep the address as its 160 bits, most significant
+/// first, which is exactly the big-endian byte order of the 20-byte form.
+/// Decoding accepts `bit` and `varbit` values of exactly 160 bits and
+/// rejects any other length. Encodes as `bit`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitAddress(pub Address);
+
+impl From<Address> for BitAddress {
+    fn from(address: Address) -> Self {
+        Self(address)
+    }
+}
+
+impl From<BitAddress> for Address {
+    fn from(address: BitAddress) -> Self {
+        address.0
+    }
+}
+
+impl Type<Postgres> for BitAddress {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("bit")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        ty.name().eq_ignore_ascii_case("bit") || ty.name().eq_ignore_ascii_case("varbit")
+    }
+}
+
+impl Encode<'_, Postgres> for BitAddress {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("bit", 4 + self.0.len());
+        // Binary format: the bit count as an `int4`, then the bits packed MSB first
+        buf.extend_from_slice(&(ADDRESS_BITS as i32).to_be_bytes());
+        buf.extend_from_slice(self.0.as_slice());
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for BitAddress {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        match value.format() {
+            PgValueFormat::Binary => {
+                let bytes = value.as_bytes()?;
+                #[cfg(feature = "trace-codec")]
+                crate::trace::decoded::<Self>(&column_type, bytes.len());
+                let (len, bits) = bytes.split_at_checked(4).ok_or("truncated bit string")?;
+                let len = i32::from_be_bytes(len.try_into()?);
+                if len != ADDRESS_BITS as i32 || bits.len() != 20 {
+                    return Err(format!("cannot decode a {len}-bit string as Address").into());
+                }
+                Ok(Self(Address::from_slice(bits)))
+            }
+            PgValueFormat::Text => {
+                let text = value.as_str()?;
+                #[cfg(feature = "trace-codec")]
+                crate::trace::decoded::<Self>(&column_type, text.len());
+                if text.len() != ADDRESS_BITS || !text.bytes().all(|b| b == b'0' || b == b'1') {
+                    return Err(format!("cannot decode bit string {text:?} as Address").into());
+                }
+                let mut bytes = [0u8; 20];
+                for (i, bit) in text.bytes().enumerate() {
+                    bytes[i / 8] |= (bit - b'0') << (7 - i % 8);
+                }
+                Ok(Self(Address::from(bytes)))
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Postgres `BYTEA[]` arrays of fixed-size primitives.
+//!
+//! sqlx only encodes `Vec<T>` for element types implementing
+//! `PgHasArrayType`, which alloy-primitives does not provide. These wrappers
+//! carry the array codec instead: bind a [`B256Array`] and read it back with
+//! `row.get::<B256Array, _>("topics")`.
+
+use std::any::type_name;
+
+use alloy_primitives::{Address, B256};
+use sqlx::{
+    Decode, Encode, PgPool, Postgres, Type,
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{PgArgumentBuffer, PgRow, PgTypeInfo, PgValueRef},
+};
+
+/// A list of fixed-size byte values stored as a Postgres `BYTEA[]`.
+///
+/// Order is preserved, empty arrays round-trip, and every element is
+/// length-checked on decode.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PgBytesArray<T>(pub Vec<T>);
+
+/// Topic hashes and other `B256` lists.
+pub type B256Array = PgBytesArray<B256>;
+
+/// Address sets, e.g. for `= ANY($1)` filters.
+pub type AddressArray = PgBytesArray<Address>;
+
+impl<T> From<Vec<T>> for PgBytesArray<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(values)
+    }
+}
+
+impl<T> Type<Postgres> for PgBytesArray<T> {
+    fn type_info() -> PgTypeInfo {
+        <Vec<Vec<u8>> as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <Vec<Vec<u8>> as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<T: AsRef<[u8]>> Encode<'_, Postgres> for PgBytesArray<T> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let raw: Vec<Vec<u8>> = self.0.iter().map(|value| value.as_ref().to_vec()).collect();
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", raw.iter().map(Vec::len).sum());
+        <Vec<Vec<u8>> as Encode<'_, Postgres>>::encode(raw, buf)
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for PgBytesArray<T>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let raw = <Vec<Vec<u8>> as Decode<'r, Postgres>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, raw.iter().map(Vec::len).sum());
+        raw.iter()
+            .enumerate()
+            .map(|(i, bytes)| {
+                T::try_from(bytes.as_slice()).map_err(|_| {
+                    format!(
+                        "array element {i} has invalid length {} for {}",
+                        bytes.len(),
+                        type_name::<T>()
+                    )
+                    .into()
+                })
+            })
+            .collect::<Result<Vec<_>, BoxDynError>>()
+            .map(Self)
+    }
+}
+
+/// Fetches the rows of `table` whose `address` column is in `addrs`.
+///
+/// Binds the whole set as a single `BYTEA[]` parameter and filters with
+/// `address = ANY($1)`, so the statement text stays the same for any set
+/// size and large sets don't run into the bind parameter limit. The table
+/// name is interpolated and must come from trusted code.
+pub async fn filter_by_addresses_any(
+    pool: &PgPool,
+    table: &str,
+    addrs: &[Address],
+) -> Result<Vec<PgRow>, sqlx::Error> {
+    sqlx::query(&format!("SELECT * FROM {table} WHERE address = ANY($1)"))
+        .bind(AddressArray::from(addrs.to_vec()))
+        .fetch_all(pool)
+        .await
+}
@@ -0,0 +1,27 @@
+//! Bloom filter predicates evaluated by Postgres.
+//!
+//! Column names are interpolated into the SQL and must come from trusted
+//! code; the mask always travels as a bind parameter.
+
+use alloy_primitives::{Bloom, FixedBytes};
+
+/// Builds a Postgres predicate that holds when the 256-byte `BYTEA` bloom in
+/// `column` contains every bit set in `mask`.
+///
+/// `param` is the 1-based placeholder number the mask is bound to; it is
+/// referenced twice in the predicate but bound once. Returns the predicate and
+/// the value to bind. Both sides are cast to `bit(2048)` so the test is a
+/// single bitwise `&` instead of one `get_bit` call per set bit:
+///
+/// ```ignore
+/// let (predicate, mask) = bloom_contains("logs_bloom", 1, &log_bloom);
+/// sqlx::query(&format!("SELECT id FROM blocks WHERE {predicate}")).bind(mask)
+/// ```
+///
+/// Like any bloom test this can match blocks that don't actually contain the
+/// log, so re-check the matches in Rust.
+pub fn bloom_contains(column: &str, param: usize, mask: &Bloom) -> (String, FixedBytes<256>) {
+    let as_bits = |expr: &str| format!("('x' || encode({expr}, 'hex'))::bit(2048)");
+    let mask_bits = as_bits(&format!("${param}"));
+    (format!("({} & {mask_bits}) = {mask_bits}", as_bits(column)), mask.0)
+}
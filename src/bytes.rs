@@ -0,0 +1,83 @@
+//! Borrowing binary columns out of the row, and storing [`Bytes`] as hex
+//! text.
+
+use alloy_primitives::{Bytes, hex};
+use sqlx::{ColumnIndex, Database, Decode, Encode, Row, Type, encode::IsNull, error::BoxDynError};
+
+use crate::address::strip_hex_prefix;
+
+/// Borrows a binary column straight out of the row buffer.
+///
+/// No allocation happens; the slice lives as long as the row. Prefer this on
+/// hot scans that only inspect or hash the payload.
+pub fn decode_bytes_ref<'r, R, I>(row: &'r R, index: I) -> Result<&'r [u8], sqlx::Error>
+where
+    R: Row,
+    I: ColumnIndex<R>,
+    &'r [u8]: Decode<'r, R::Database> + Type<R::Database>,
+{
+    row.try_get(index)
+}
+
+/// [`Bytes`] stored as `0x`-prefixed lowercase hex in a text column, for
+/// payloads that should stay readable and greppable in the database.
+///
+/// Decoding accepts the `0x`/`0X` prefix or bare hex, in any case.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BytesHex(pub Bytes);
+
+impl From<Bytes> for BytesHex {
+    fn from(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<BytesHex> for Bytes {
+    fn from(bytes: BytesHex) -> Self {
+        bytes.0
+    }
+}
+
+impl<DB: Database> Type<DB> for BytesHex
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for BytesHex
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let text = hex::encode_prefixed(&self.0);
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("text", text.len());
+        <String as Encode<'q, DB>>::encode(text, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for BytesHex
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let text = <&'r str as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, text.len());
+        hex::decode(strip_hex_prefix(text))
+            .map(|bytes| Self(bytes.into()))
+            .map_err(|e| format!("invalid hex bytes {text:?}: {e}").into())
+    }
+}
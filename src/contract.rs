@@ -0,0 +1,38 @@
+//! Contract address derivation for deployment indexes.
+//!
+//! No supported database can compute Keccak-256, so a derived address can't
+//! be a generated column. Derive it in Rust when inserting the deployer and
+//! nonce (or salt) and bind it alongside them.
+
+use alloy_primitives::{Address, B256, keccak256};
+
+/// Returns the address of a contract deployed with `CREATE` by `deployer` at
+/// account nonce `nonce`: the last 20 bytes of `keccak256(rlp([deployer, nonce]))`.
+pub fn derive_create_address(deployer: Address, nonce: u64) -> Address {
+    // The RLP list is short enough to build by hand, which avoids pulling in
+    // the `rlp` feature of alloy-primitives.
+    let nonce_bytes = nonce.to_be_bytes();
+    let significant = &nonce_bytes[nonce.leading_zeros() as usize / 8..];
+    let mut payload = Vec::with_capacity(30);
+    payload.push(0x80 + 20);
+    payload.extend_from_slice(deployer.as_slice());
+    match significant {
+        [] => payload.push(0x80),
+        [byte] if *byte < 0x80 => payload.push(*byte),
+        bytes => {
+            payload.push(0x80 + bytes.len() as u8);
+            payload.extend_from_slice(bytes);
+        }
+    }
+
+    let mut list = Vec::with_capacity(payload.len() + 1);
+    list.push(0xc0 + payload.len() as u8);
+    list.extend_from_slice(&payload);
+    Address::from_word(keccak256(list))
+}
+
+/// Returns the address of a contract deployed with `CREATE2` (EIP-1014) by
+/// `deployer` with `salt` and the Keccak-256 hash of its init code.
+pub fn derive_create2_address(deployer: Address, salt: B256, init_code_hash: B256) -> Address {
+    deployer.create2(salt, init_code_hash)
+}
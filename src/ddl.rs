@@ -0,0 +1,111 @@
+//! DDL fragments for primitive columns, meant to be pasted into migrations.
+
+use crate::dialect::{Backend, Dialect};
+
+/// Returns a Postgres `CHECK` constraint pinning a `BYTEA` column to exactly
+/// `n` bytes.
+///
+/// `BYTEA` has no length of its own, so without this a `FixedBytes<32>`
+/// column will happily store 31 bytes; the alloy-primitives decode still
+/// rejects such values, but only when they are read back.
+pub fn fixed_bytes_check(col: &str, n: usize) -> String {
+    format!("CHECK (octet_length({col}) = {n})")
+}
+
+/// Returns a Postgres `CREATE INDEX` on `lower(column)` for a text address
+/// column, so case-insensitive lookups built with
+/// [`lower_address_eq`](crate::sql::lower_address_eq) can use an index even
+/// when the column holds checksummed text.
+pub fn lower_address_index(table: &str, column: &str) -> String {
+    format!("CREATE INDEX IF NOT EXISTS {table}_{column}_lower_idx ON {table} (lower({column}))")
+}
+
+/// Returns the decimal precision needed to hold any `bits`-bit unsigned
+/// integer, i.e. the number of digits of `2^bits - 1`: `ceil(bits * log10(2))`.
+///
+/// Returns at least 1, since `NUMERIC(0)` is not a valid type.
+pub fn numeric_precision_for_bits(bits: u32) -> u32 {
+    // `bits * log10(2)` is irrational for every non-zero `bits`, so `ceil`
+    // never lands on an exact integer boundary.
+    ((f64::from(bits) * std::f64::consts::LOG10_2).ceil() as u32).max(1)
+}
+
+/// Builds column definitions for alloy primitives in a backend's dialect.
+///
+/// Types match the binary codecs of alloy-primitives: `BYTEA` on Postgres,
+/// `BINARY(N)` on MySQL and `BLOB` on SQLite. `U256` is stored as its 32-byte
+/// big-endian form.
+///
+/// ```
+/// use test_alloy_sqlx::{Dialect, EthColumns};
+///
+/// let columns = EthColumns::new(Dialect::MySql).address("owner").u256("balance").build();
+/// assert_eq!(columns, "owner BINARY(20) NOT NULL, balance BINARY(32) NOT NULL");
+/// ```
+#[derive(Clone, Debug)]
+pub struct EthColumns {
+    dialect: Dialect,
+    columns: Vec<String>,
+}
+
+impl EthColumns {
+    /// Starts an empty column list for `dialect`.
+    pub fn new(dialect: Dialect) -> Self {
+        Self { dialect, columns: Vec::new() }
+    }
+
+    /// Starts an empty column list for the backend `DB`.
+    pub fn for_backend<DB: Backend>() -> Self {
+        Self::new(DB::DIALECT)
+    }
+
+    /// Adds a 20-byte `Address` column.
+    pub fn address(self, name: &str) -> Self {
+        self.binary(name, 20)
+    }
+
+    /// Adds a 32-byte `B256` column.
+    pub fn hash32(self, name: &str) -> Self {
+        self.binary(name, 32)
+    }
+
+    /// Adds a `U256` column holding its 32-byte big-endian form.
+    pub fn u256(self, name: &str) -> Self {
+        self.binary(name, 32)
+    }
+
+    /// Adds a decimal column wide enough for any `bits`-bit unsigned integer.
+    ///
+    /// Uses `NUMERIC(p, 0)` on Postgres (read with `U256Numeric`) and
+    /// `DECIMAL(p, 0)` on MySQL, where `p` is [`numeric_precision_for_bits`].
+    /// MySQL caps `DECIMAL` at 65 digits, so wider values and all SQLite
+    /// columns are decimal text instead (read with `U256Decimal`).
+    pub fn uint_numeric(mut self, name: &str, bits: u32) -> Self {
+        let precision = numeric_precision_for_bits(bits);
+        let ty = match self.dialect {
+            Dialect::Postgres => format!("NUMERIC({precision}, 0)"),
+            Dialect::MySql if precision <= 65 => format!("DECIMAL({precision}, 0)"),
+            Dialect::MySql => format!("VARCHAR({precision})"),
+            Dialect::Sqlite => "TEXT".to_string(),
+        };
+        self.columns.push(format!("{name} {ty} NOT NULL"));
+        self
+    }
+
+    /// Adds a binary column of exactly `len` bytes.
+    pub fn binary(mut self, name: &str, len: usize) -> Self {
+        let ty = self.dialect.binary_type(len);
+        self.columns.push(format!("{name} {ty} NOT NULL"));
+        self
+    }
+
+    /// Returns the individual column definitions.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the column definitions joined for a `CREATE TABLE` body.
+    pub fn build(&self) -> String {
+        self.columns.join(", ")
+    }
+}
@@ -0,0 +1,48 @@
+//! Human-readable descriptions of how primitives are stored.
+
+use alloy_primitives::{Address, FixedBytes, U256, hex};
+
+use crate::dialect::Dialect;
+
+/// A primitive stored through its fixed-width big-endian binary codec.
+pub trait BinaryEncoded {
+    /// Returns the exact bytes the codec writes.
+    fn encoded_bytes(&self) -> Vec<u8>;
+}
+
+impl BinaryEncoded for Address {
+    fn encoded_bytes(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl<const N: usize> BinaryEncoded for FixedBytes<N> {
+    fn encoded_bytes(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl BinaryEncoded for U256 {
+    fn encoded_bytes(&self) -> Vec<u8> {
+        self.to_be_bytes::<32>().to_vec()
+    }
+}
+
+/// Describes the column type and bytes `value` produces on `dialect`.
+///
+/// For example `"Postgres: BYTEA, 20 bytes, big-endian, 0x742d…"`. Meant for
+/// logs and schema design, not for parsing.
+pub fn describe_encoding<T: BinaryEncoded + ?Sized>(value: &T, dialect: Dialect) -> String {
+    let bytes = value.encoded_bytes();
+    let backend = match dialect {
+        Dialect::Postgres => "Postgres",
+        Dialect::MySql => "MySQL",
+        Dialect::Sqlite => "SQLite",
+    };
+    format!(
+        "{backend}: {}, {} bytes, big-endian, {}",
+        dialect.binary_type(bytes.len()),
+        bytes.len(),
+        hex::encode_prefixed(&bytes),
+    )
+}
@@ -0,0 +1,152 @@
+//! Per-backend SQL dialect differences.
+
+use sqlx::{
+    Database, MySql, Postgres, Sqlite, mysql::MySqlQueryResult, postgres::PgQueryResult,
+    sqlite::SqliteQueryResult,
+};
+
+/// SQL dialect of one of the supported backends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl Dialect {
+    /// Returns the placeholder for the 1-based bind parameter `n`.
+    pub fn placeholder(self, n: usize) -> String {
+        match self {
+            Self::Postgres => format!("${n}"),
+            Self::MySql | Self::Sqlite => "?".to_string(),
+        }
+    }
+
+    /// Returns an expression reading an integer `column` as a 64-bit integer,
+    /// so `SERIAL`/`INT` ids decode as `i64` on every backend.
+    pub(crate) fn bigint(self, column: &str) -> String {
+        match self {
+            Self::Postgres => format!("{column}::bigint"),
+            Self::MySql => format!("CAST({column} AS SIGNED)"),
+            Self::Sqlite => column.to_string(),
+        }
+    }
+
+    /// Returns the column type for a binary value of exactly `len` bytes.
+    ///
+    /// MySQL's `BINARY(len)` pads shorter values, so `len` must match the
+    /// stored type's width exactly.
+    pub fn binary_type(self, len: usize) -> String {
+        match self {
+            Self::Postgres => "BYTEA".to_string(),
+            Self::MySql => format!("BINARY({len})"),
+            Self::Sqlite => "BLOB".to_string(),
+        }
+    }
+}
+
+/// A sqlx [`Database`] whose SQL dialect is known at compile time.
+pub trait Backend: Database {
+    const DIALECT: Dialect;
+
+    /// Returns the number of rows changed by a statement.
+    ///
+    /// sqlx exposes this on each concrete query result type but not through
+    /// [`Database`], so generic helpers go through this method.
+    fn rows_affected(result: &Self::QueryResult) -> u64;
+
+    /// Returns the auto-increment id generated by an `INSERT`, if the backend
+    /// reports one. Postgres doesn't; use `RETURNING` there.
+    fn last_insert_id(result: &Self::QueryResult) -> Option<i64>;
+
+    /// Returns the number of value bytes held in an argument buffer, so codecs
+    /// that delegate to another `Encode` can trace what it wrote.
+    #[cfg(feature = "trace-codec")]
+    fn argument_len(buf: &<Self as Database>::ArgumentBuffer<'_>) -> usize;
+
+    /// Returns the size in bytes of a value about to be decoded.
+    #[cfg(feature = "trace-codec")]
+    fn value_len(value: &<Self as Database>::ValueRef<'_>) -> usize;
+}
+
+impl Backend for Postgres {
+    const DIALECT: Dialect = Dialect::Postgres;
+
+    fn rows_affected(result: &PgQueryResult) -> u64 {
+        result.rows_affected()
+    }
+
+    fn last_insert_id(_result: &PgQueryResult) -> Option<i64> {
+        None
+    }
+
+    #[cfg(feature = "trace-codec")]
+    fn argument_len(buf: &sqlx::postgres::PgArgumentBuffer) -> usize {
+        buf.len()
+    }
+
+    #[cfg(feature = "trace-codec")]
+    fn value_len(value: &sqlx::postgres::PgValueRef<'_>) -> usize {
+        value.as_bytes().map_or(0, <[u8]>::len)
+    }
+}
+
+impl Backend for MySql {
+    const DIALECT: Dialect = Dialect::MySql;
+
+    fn rows_affected(result: &MySqlQueryResult) -> u64 {
+        result.rows_affected()
+    }
+
+    fn last_insert_id(result: &MySqlQueryResult) -> Option<i64> {
+        i64::try_from(result.last_insert_id()).ok()
+    }
+
+    #[cfg(feature = "trace-codec")]
+    fn argument_len(buf: &Vec<u8>) -> usize {
+        buf.len()
+    }
+
+    #[cfg(feature = "trace-codec")]
+    fn value_len(value: &sqlx::mysql::MySqlValueRef<'_>) -> usize {
+        use sqlx::{Decode, Value, ValueRef};
+
+        let owned = value.to_owned();
+        <&[u8] as Decode<'_, MySql>>::decode(owned.as_ref()).map_or(0, <[u8]>::len)
+    }
+}
+
+impl Backend for Sqlite {
+    const DIALECT: Dialect = Dialect::Sqlite;
+
+    fn rows_affected(result: &SqliteQueryResult) -> u64 {
+        result.rows_affected()
+    }
+
+    fn last_insert_id(result: &SqliteQueryResult) -> Option<i64> {
+        Some(result.last_insert_rowid())
+    }
+
+    #[cfg(feature = "trace-codec")]
+    fn argument_len(buf: &Vec<sqlx::sqlite::SqliteArgumentValue<'_>>) -> usize {
+        use sqlx::sqlite::SqliteArgumentValue;
+
+        buf.iter()
+            .map(|value| match value {
+                SqliteArgumentValue::Text(text) => text.len(),
+                SqliteArgumentValue::Blob(blob) => blob.len(),
+                SqliteArgumentValue::Null => 0,
+                SqliteArgumentValue::Int(_) => size_of::<i32>(),
+                _ => size_of::<i64>(),
+            })
+            .sum()
+    }
+
+    #[cfg(feature = "trace-codec")]
+    fn value_len(value: &sqlx::sqlite::SqliteValueRef<'_>) -> usize {
+        use sqlx::{Decode, Value, ValueRef};
+
+        let owned = value.to_owned();
+        <&[u8] as Decode<'_, Sqlite>>::decode(owned.as_ref()).map_or(0, <[u8]>::len)
+    }
+}
@@ -0,0 +1,67 @@
+//! Event rows: batched writes of `(Address, B256, U256)` tuples and, with
+//! the `time` feature, timestamped reads.
+
+use alloy_primitives::{Address, B256, U256};
+use sqlx::{Database, Encode, Executor, IntoArguments, Transaction, Type};
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
+use crate::dialect::Backend;
+
+/// Inserts `events` into `table` inside `tx` and commits, returning the
+/// number of rows written.
+///
+/// The table needs `address`, `tx_hash` and `amount` columns using the binary
+/// codecs. If any insert fails the transaction is rolled back and nothing is
+/// written.
+pub async fn insert_events<DB>(
+    mut tx: Transaction<'_, DB>,
+    table: &str,
+    events: &[(Address, B256, U256)],
+) -> Result<usize, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c mut <DB as Database>::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    for<'q> B256: Encode<'q, DB>,
+    for<'q> U256: Encode<'q, DB>,
+    Address: Type<DB>,
+    B256: Type<DB>,
+    U256: Type<DB>,
+{
+    let dialect = DB::DIALECT;
+    let sql = format!(
+        "INSERT INTO {table} (address, tx_hash, amount) VALUES ({}, {}, {})",
+        dialect.placeholder(1),
+        dialect.placeholder(2),
+        dialect.placeholder(3),
+    );
+
+    // Returning early drops `tx`, which rolls it back; the insert error is
+    // what the caller sees, never a failure of the rollback itself
+    for (address, tx_hash, amount) in events {
+        sqlx::query(&sql)
+            .bind(*address)
+            .bind(*tx_hash)
+            .bind(*amount)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+
+    Ok(events.len())
+}
+
+/// A primitive value emitted by `address` at `block_time`.
+///
+/// Maps rows with `value`, `address` and `block_time` columns; `block_time`
+/// is a `TIMESTAMPTZ` on Postgres, `TIMESTAMP` on MySQL and ISO-8601 text
+/// on SQLite.
+#[cfg(feature = "time")]
+#[derive(Clone, Debug, PartialEq, Eq, sqlx::FromRow)]
+pub struct EthEvent<T> {
+    pub value: T,
+    pub address: Address,
+    pub block_time: OffsetDateTime,
+}
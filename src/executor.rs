@@ -0,0 +1,148 @@
+//! Executor extensions and single-value query helpers for alloy primitives.
+
+use std::future::Future;
+
+use alloy_primitives::{Address, U256};
+use futures_util::{Stream, StreamExt};
+use sqlx::{ColumnIndex, Database, Decode, Executor, FromRow, IntoArguments, Row, Type, query::Query};
+
+/// Typed single-value fetches, implemented for every [`Executor`].
+///
+/// Each method runs a query that must return exactly one row and decodes its
+/// first column with the alloy-primitives codec.
+pub trait PrimitiveExecutor<'c>: Executor<'c> {
+    /// Fetches a single [`Address`].
+    fn fetch_address<'q>(self, sql: &'q str) -> impl Future<Output = Result<Address, sqlx::Error>> + Send
+    where
+        'c: 'q,
+        Self: 'q,
+        Address: for<'r> Decode<'r, Self::Database> + Type<Self::Database>,
+        usize: ColumnIndex<<Self::Database as Database>::Row>,
+    {
+        fetch_scalar(self, sql)
+    }
+
+    /// Fetches a single [`U256`].
+    fn fetch_u256<'q>(self, sql: &'q str) -> impl Future<Output = Result<U256, sqlx::Error>> + Send
+    where
+        'c: 'q,
+        Self: 'q,
+        U256: for<'r> Decode<'r, Self::Database> + Type<Self::Database>,
+        usize: ColumnIndex<<Self::Database as Database>::Row>,
+    {
+        fetch_scalar(self, sql)
+    }
+}
+
+impl<'c, E: Executor<'c>> PrimitiveExecutor<'c> for E {}
+
+async fn fetch_scalar<'c, 'q, E, T>(executor: E, sql: &'q str) -> Result<T, sqlx::Error>
+where
+    'c: 'q,
+    E: Executor<'c> + 'q,
+    T: for<'r> Decode<'r, E::Database> + Type<E::Database>,
+    usize: ColumnIndex<<E::Database as Database>::Row>,
+{
+    let row = executor.fetch_one(sql).await?;
+    row.try_get(0)
+}
+
+/// Outcome of looking up a single, possibly `NULL`, address column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FindResult {
+    /// The query returned no rows.
+    NoRow,
+    /// A row was returned but its address column was `NULL`.
+    NullValue,
+    /// A row was returned with a non-`NULL` address.
+    Found(Address),
+}
+
+impl FindResult {
+    /// Collapses both [`FindResult::NoRow`] and [`FindResult::NullValue`] into `None`.
+    pub fn into_option(self) -> Option<Address> {
+        match self {
+            Self::Found(address) => Some(address),
+            Self::NoRow | Self::NullValue => None,
+        }
+    }
+}
+
+/// Runs `query` and decodes the first column of the first row as an address,
+/// treating both a missing row and a `NULL` column as `None`.
+///
+/// Use [`find_address_result`] when the two cases must be told apart.
+pub async fn find_address<'q, 'c, E, DB>(
+    executor: E,
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+) -> Result<Option<Address>, sqlx::Error>
+where
+    DB: Database,
+    E: Executor<'c, Database = DB>,
+    <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    find_address_result(executor, query).await.map(FindResult::into_option)
+}
+
+/// Runs `query` and decodes the first column of the first row as an address,
+/// returning `None` only when there is no row.
+///
+/// Unlike [`find_address`], a `NULL` column is a decode error, which suits
+/// `NOT NULL` address columns where `NULL` would point to a bad query.
+pub async fn get_optional_address<'q, 'c, E, DB>(
+    executor: E,
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+) -> Result<Option<Address>, sqlx::Error>
+where
+    DB: Database,
+    E: Executor<'c, Database = DB>,
+    <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    query.fetch_optional(executor).await?.map(|row| row.try_get(0)).transpose()
+}
+
+/// Runs `query` and reports whether the row was missing, `NULL`, or found.
+pub async fn find_address_result<'q, 'c, E, DB>(
+    executor: E,
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+) -> Result<FindResult, sqlx::Error>
+where
+    DB: Database,
+    E: Executor<'c, Database = DB>,
+    <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let Some(row) = query.fetch_optional(executor).await? else {
+        return Ok(FindResult::NoRow);
+    };
+    Ok(match row.try_get::<Option<Address>, _>(0)? {
+        Some(address) => FindResult::Found(address),
+        None => FindResult::NullValue,
+    })
+}
+
+/// Runs `query` and yields each row decoded as `T`.
+///
+/// Rows are pulled from the database only as the stream is polled, so a slow
+/// consumer applies backpressure instead of buffering the result set. Collect
+/// with `try_collect` or walk it with `try_next`; the first decode error ends
+/// the stream.
+pub fn typed_stream<'e, 'q, 'c, E, DB, T>(
+    executor: E,
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+) -> impl Stream<Item = Result<T, sqlx::Error>> + Send + Unpin + 'e
+where
+    'q: 'e,
+    'c: 'e,
+    DB: Database,
+    E: Executor<'c, Database = DB> + 'e,
+    <DB as Database>::Arguments<'q>: IntoArguments<'q, DB> + 'e,
+    T: for<'r> FromRow<'r, DB::Row> + Send + 'e,
+{
+    query.fetch(executor).map(|row| row.and_then(|row| T::from_row(&row)))
+}
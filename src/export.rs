@@ -0,0 +1,44 @@
+//! Streaming exports of primitive columns.
+//!
+//! Table names are interpolated into the SQL and must come from trusted code.
+
+use std::io::Write;
+
+use alloy_primitives::{Address, U256};
+use futures_util::TryStreamExt;
+use sqlx::{ColumnIndex, Database, Decode, Executor, IntoArguments, Pool, Type};
+
+/// Writes the `address` and `balance` columns of `table` to `writer` as CSV,
+/// returning the number of data rows written.
+///
+/// Rows are streamed from the database and written one at a time, so the
+/// table is never held in memory. Addresses are written as EIP-55 checksummed
+/// hex and balances as decimal, under an `address,balance` header. Neither
+/// format contains commas or quotes, so no escaping is needed. Rows come in
+/// `address` order.
+pub async fn export_addresses_csv<DB, W>(
+    pool: &Pool<DB>,
+    table: &str,
+    mut writer: W,
+) -> Result<u64, sqlx::Error>
+where
+    DB: Database,
+    W: Write,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    U256: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let sql = format!("SELECT address, balance FROM {table} ORDER BY address");
+    let mut rows = sqlx::query_as::<DB, (Address, U256)>(&sql).fetch(pool);
+
+    writeln!(writer, "address,balance")?;
+    let mut written = 0;
+    while let Some((address, balance)) = rows.try_next().await? {
+        writeln!(writer, "{},{balance}", address.to_checksum(None))?;
+        written += 1;
+    }
+    writer.flush()?;
+    Ok(written)
+}
@@ -0,0 +1,200 @@
+//! Wrappers for `FixedBytes<N>` columns: alternative storage layouts and
+//! width-checked binds.
+
+use std::fmt;
+
+use alloy_primitives::FixedBytes;
+use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+use crate::dialect::Dialect;
+
+/// A [`FixedBytes`] stored with its bytes reversed, for legacy schemas that
+/// write hashes little-endian.
+///
+/// The wrapped value keeps the usual big-endian order in memory; only the
+/// stored bytes are reversed, on encode and again on decode. Decoding
+/// requires exactly `N` bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedBytesLe<const N: usize>(pub FixedBytes<N>);
+
+impl<const N: usize> From<FixedBytes<N>> for FixedBytesLe<N> {
+    fn from(value: FixedBytes<N>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> From<FixedBytesLe<N>> for FixedBytes<N> {
+    fn from(value: FixedBytesLe<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> FixedBytesLe<N> {
+    /// Returns the stored, byte-reversed form.
+    pub fn to_le_bytes(&self) -> [u8; N] {
+        let mut bytes = self.0.0;
+        bytes.reverse();
+        bytes
+    }
+
+    /// Parses a stored little-endian blob of exactly `N` bytes.
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, BoxDynError> {
+        let mut array: [u8; N] = bytes.try_into().map_err(|_| {
+            format!("invalid FixedBytes<{N}> length: expected {N} bytes, got {}", bytes.len())
+        })?;
+        array.reverse();
+        Ok(Self(FixedBytes(array)))
+    }
+}
+
+impl<const N: usize, DB: Database> Type<DB> for FixedBytesLe<N>
+where
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, const N: usize, DB: Database> Encode<'q, DB> for FixedBytesLe<N>
+where
+    Vec<u8>: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let bytes = self.to_le_bytes().to_vec();
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", bytes.len());
+        <Vec<u8> as Encode<'q, DB>>::encode(bytes, buf)
+    }
+}
+
+impl<'r, const N: usize, DB: Database> Decode<'r, DB> for FixedBytesLe<N>
+where
+    Vec<u8>: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let bytes = <Vec<u8> as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, bytes.len());
+        Self::from_le_slice(&bytes)
+    }
+}
+
+/// A [`FixedBytes`] tied to a column declared exactly `N` bytes wide.
+///
+/// Naming the width at the bind site turns a width mismatch into a compile
+/// error: only a `FixedBytes<N>` converts into a `FixedColumn<N>`, so a hash
+/// can't be bound to an address-sized column by accident. Untyped input goes
+/// through [`FixedColumn::try_from_slice`], which checks the length before
+/// the query runs. Use [`FixedColumn::column_type`] to declare the matching
+/// column.
+///
+/// Unlike a plain `FixedBytes<N>`, whose decode fails with an opaque message,
+/// a stored value of any other width (a `BINARY(M)` column padded to `M`
+/// bytes, a truncated blob) fails with a [`ColumnWidthMismatch`] naming both
+/// widths, which callers can downcast from the column decode error.
+///
+/// ```compile_fail
+/// use alloy_primitives::B256;
+/// use test_alloy_sqlx::FixedColumn;
+///
+/// // A 32-byte hash doesn't fit a BINARY(20) column
+/// let column: FixedColumn<20> = B256::ZERO.into();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedColumn<const N: usize>(pub FixedBytes<N>);
+
+/// Returned when a value's width doesn't match the `N` bytes of a
+/// [`FixedColumn<N>`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnWidthMismatch {
+    /// The declared width `N`.
+    pub expected: usize,
+    /// The width of the value bound or read.
+    pub actual: usize,
+}
+
+impl fmt::Display for ColumnWidthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes don't fit a {}-byte column", self.actual, self.expected)
+    }
+}
+
+impl std::error::Error for ColumnWidthMismatch {}
+
+impl<const N: usize> From<FixedBytes<N>> for FixedColumn<N> {
+    fn from(value: FixedBytes<N>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> From<FixedColumn<N>> for FixedBytes<N> {
+    fn from(value: FixedColumn<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> FixedColumn<N> {
+    /// Checks that `bytes` is exactly `N` bytes long.
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, ColumnWidthMismatch> {
+        let array: [u8; N] = bytes
+            .try_into()
+            .map_err(|_| ColumnWidthMismatch { expected: N, actual: bytes.len() })?;
+        Ok(Self(FixedBytes(array)))
+    }
+
+    /// Returns the column type holding exactly `N` bytes in `dialect`.
+    pub fn column_type(dialect: Dialect) -> String {
+        dialect.binary_type(N)
+    }
+}
+
+impl<const N: usize, DB: Database> Type<DB> for FixedColumn<N>
+where
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, const N: usize, DB: Database> Encode<'q, DB> for FixedColumn<N>
+where
+    Vec<u8>: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", N);
+        <Vec<u8> as Encode<'q, DB>>::encode(self.0.to_vec(), buf)
+    }
+}
+
+impl<'r, const N: usize, DB: Database> Decode<'r, DB> for FixedColumn<N>
+where
+    Vec<u8>: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let bytes = <Vec<u8> as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, bytes.len());
+        Ok(Self::try_from_slice(&bytes)?)
+    }
+}
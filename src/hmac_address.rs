@@ -0,0 +1,172 @@
+//! Addresses stored alongside an HMAC-SHA256 tag for tamper detection.
+
+use std::{fmt, hash, marker::PhantomData};
+
+use alloy_primitives::Address;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Length of the stored blob: 20 address bytes followed by a 32-byte tag.
+pub const HMAC_ADDRESS_LEN: usize = 20 + 32;
+
+/// Supplies the application key for [`HmacAddress`].
+///
+/// Implement it on a unit type; the key can be a constant or loaded once at
+/// startup (e.g. from a `OnceLock`).
+///
+/// ```ignore
+/// struct RegistryKey;
+///
+/// impl HmacKey for RegistryKey {
+///     fn key() -> &'static [u8] {
+///         REGISTRY_KEY.get().expect("key loaded at startup")
+///     }
+/// }
+/// ```
+pub trait HmacKey {
+    fn key() -> &'static [u8];
+}
+
+/// An [`Address`] stored as `[20 addr][32 mac]` in a binary column, tagged
+/// under the key from `K`.
+///
+/// Decoding recomputes the tag and fails with [`HmacMismatch`] if it doesn't
+/// match, so a decoded value is always verified.
+pub struct HmacAddress<K> {
+    address: Address,
+    mac: [u8; 32],
+    key: PhantomData<fn() -> K>,
+}
+
+// Manual impls: derives would needlessly require `K` to implement the traits.
+impl<K> Clone for HmacAddress<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for HmacAddress<K> {}
+
+impl<K> fmt::Debug for HmacAddress<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacAddress").field("address", &self.address).field("mac", &self.mac).finish()
+    }
+}
+
+impl<K> PartialEq for HmacAddress<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address && self.mac == other.mac
+    }
+}
+
+impl<K> Eq for HmacAddress<K> {}
+
+impl<K> hash::Hash for HmacAddress<K> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.address.hash(state);
+        self.mac.hash(state);
+    }
+}
+
+/// The stored tag does not match the address under the given key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HmacMismatch {
+    /// The address whose tag failed to verify.
+    pub address: Address,
+}
+
+impl fmt::Display for HmacMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HMAC verification failed for address {}", self.address)
+    }
+}
+
+impl std::error::Error for HmacMismatch {}
+
+fn mac_for(address: Address, key: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(address.as_slice());
+    mac
+}
+
+impl<K: HmacKey> HmacAddress<K> {
+    /// Tags `address` with an HMAC-SHA256 under `K`'s key.
+    pub fn new(address: Address) -> Self {
+        let mac = mac_for(address, K::key()).finalize().into_bytes().into();
+        Self { address, mac, key: PhantomData }
+    }
+
+    /// Returns the address.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the stored blob.
+    pub fn to_bytes(&self) -> [u8; HMAC_ADDRESS_LEN] {
+        let mut bytes = [0u8; HMAC_ADDRESS_LEN];
+        bytes[..20].copy_from_slice(self.address.as_slice());
+        bytes[20..].copy_from_slice(&self.mac);
+        bytes
+    }
+
+    /// Parses a stored blob, checking its length and verifying its tag.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BoxDynError> {
+        if bytes.len() != HMAC_ADDRESS_LEN {
+            return Err(format!(
+                "invalid HMAC address length: expected {HMAC_ADDRESS_LEN} bytes, got {}",
+                bytes.len()
+            )
+            .into());
+        }
+        let (address, mac) = bytes.split_at(20);
+        let address = Address::from_slice(address);
+        mac_for(address, K::key())
+            .verify_slice(mac)
+            .map_err(|_| HmacMismatch { address })?;
+        Ok(Self { address, mac: mac.try_into()?, key: PhantomData })
+    }
+}
+
+impl<K, DB: Database> Type<DB> for HmacAddress<K>
+where
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, K: HmacKey, DB: Database> Encode<'q, DB> for HmacAddress<K>
+where
+    Vec<u8>: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", HMAC_ADDRESS_LEN);
+        <Vec<u8> as Encode<'q, DB>>::encode(self.to_bytes().to_vec(), buf)
+    }
+}
+
+impl<'r, K: HmacKey, DB: Database> Decode<'r, DB> for HmacAddress<K>
+where
+    Vec<u8>: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let bytes = <Vec<u8> as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, bytes.len());
+        Self::from_bytes(&bytes)
+    }
+}
@@ -0,0 +1,88 @@
+//! JSON documents keyed by primitives.
+//!
+//! alloy-primitives serializes `U256` as `0x` hex, and `serde_json` can't
+//! use an `Address` map key directly. [`AddressBalanceMap`] fixes both for
+//! the common `address -> amount` object.
+
+use std::collections::{BTreeMap, HashMap};
+
+use alloy_primitives::{Address, U256};
+use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError, types::Json};
+
+use crate::{address::parse_address_str, dialect::Backend, numeric::parse_decimal_u256};
+
+/// The JSON shape of an [`AddressBalanceMap`].
+type MapRepr = Json<BTreeMap<String, String>>;
+
+/// A `HashMap<Address, U256>` stored as a JSON object (`JSONB` on Postgres,
+/// `JSON` on MySQL, `TEXT` on SQLite).
+///
+/// Keys are lowercase `0x` hex addresses and values decimal strings, e.g.
+/// `{"0xdac1...1ec7": "1000"}`, which keeps amounts past 2^53 exact for
+/// JSON consumers. Keys are written sorted. Decoding accepts keys in any
+/// case, but fails if two keys name the same address.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddressBalanceMap(pub HashMap<Address, U256>);
+
+impl From<HashMap<Address, U256>> for AddressBalanceMap {
+    fn from(map: HashMap<Address, U256>) -> Self {
+        Self(map)
+    }
+}
+
+impl From<AddressBalanceMap> for HashMap<Address, U256> {
+    fn from(map: AddressBalanceMap) -> Self {
+        map.0
+    }
+}
+
+impl<DB: Database> Type<DB> for AddressBalanceMap
+where
+    MapRepr: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <MapRepr as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <MapRepr as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Backend> Encode<'q, DB> for AddressBalanceMap
+where
+    MapRepr: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let repr: BTreeMap<_, _> =
+            self.0.iter().map(|(address, amount)| (format!("{address:#x}"), amount.to_string())).collect();
+        #[cfg(feature = "trace-codec")]
+        let start = DB::argument_len(buf);
+        let is_null = <MapRepr as Encode<'q, DB>>::encode(Json(repr), buf)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("json", DB::argument_len(buf) - start);
+        Ok(is_null)
+    }
+}
+
+impl<'r, DB: Backend> Decode<'r, DB> for AddressBalanceMap
+where
+    MapRepr: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&crate::trace::column_type(&value), DB::value_len(&value));
+        let Json(repr) = <MapRepr as Decode<'r, DB>>::decode(value)?;
+        let mut map = HashMap::with_capacity(repr.len());
+        for (key, amount) in repr {
+            let address = parse_address_str(&key)?;
+            if map.insert(address, parse_decimal_u256(&amount)?).is_some() {
+                return Err(format!("duplicate address {address} in JSON map").into());
+            }
+        }
+        Ok(Self(map))
+    }
+}
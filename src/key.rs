@@ -0,0 +1,55 @@
+//! Lookups shared by address-keyed and hash-keyed tables.
+//!
+//! Table and column names are interpolated into the SQL and must come from
+//! trusted code.
+
+use alloy_primitives::{Address, B256, Selector};
+use sqlx::{Database, Decode, Encode, Executor, FromRow, IntoArguments, Pool, Type};
+
+use crate::dialect::Backend;
+
+/// A primitive usable as a lookup key: binds and decodes with its binary
+/// codec on `DB`.
+pub trait EthKey<DB: Database>:
+    for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB> + Type<DB> + Copy + Send + Sync + 'static
+{
+}
+
+impl<DB: Database> EthKey<DB> for Address where
+    Self: for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB> + Type<DB>
+{
+}
+
+impl<DB: Database> EthKey<DB> for B256 where
+    Self: for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB> + Type<DB>
+{
+}
+
+impl<DB: Database> EthKey<DB> for Selector where
+    Self: for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB> + Type<DB>
+{
+}
+
+/// Fetches the row of `table` whose `column` equals `key`, if any.
+///
+/// Selects `*`, so `T` can pick whichever columns it needs. If several rows
+/// match, the first one returned by the database wins.
+pub async fn get_by_key<DB, K, T>(
+    pool: &Pool<DB>,
+    table: &str,
+    column: &str,
+    key: K,
+) -> Result<Option<T>, sqlx::Error>
+where
+    DB: Backend,
+    K: EthKey<DB>,
+    T: for<'r> FromRow<'r, DB::Row> + Send + Unpin,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+{
+    let p1 = DB::DIALECT.placeholder(1);
+    sqlx::query_as(&format!("SELECT * FROM {table} WHERE {column} = {p1}"))
+        .bind(key)
+        .fetch_optional(pool)
+        .await
+}
@@ -0,0 +1,115 @@
+//! sqlx helpers built on top of the alloy-primitives `sqlx` feature.
+//!
+//! alloy-primitives already maps `Address` and `FixedBytes<N>` to binary
+//! columns on every backend; this crate adds the wrappers and query helpers
+//! that sit around those impls.
+//!
+//! # Byte order
+//!
+//! `FixedBytes<N>` (and `Address`) are stored exactly as `as_slice()`
+//! returns them, byte for byte, and decode copies the column back in the same
+//! order. No backend reverses or pads them, so the stored bytes are identical
+//! on Postgres, MySQL and SQLite. `U256` uses its big-endian form.
+//!
+//! Decoding requires exactly `N` bytes and fails otherwise. On MySQL this
+//! matters for the column declaration: `BINARY(M)` right-pads shorter values
+//! with zero bytes, so a `FixedBytes<N>` written to a column with `M != N`
+//! reads back as `M` bytes and no longer decodes. Declare the column as
+//! exactly `BINARY(N)` (see [`Dialect::binary_type`]).
+
+pub mod address;
+pub mod array;
+pub mod bloom;
+pub mod bytes;
+pub mod contract;
+pub mod copy;
+pub mod ddl;
+pub mod diagnostics;
+pub mod dialect;
+pub mod events;
+pub mod executor;
+pub mod export;
+pub mod fixed;
+#[cfg(feature = "hmac")]
+pub mod hmac_address;
+pub mod json;
+pub mod key;
+pub mod log;
+pub mod migrate;
+pub mod numeric;
+pub mod packed;
+pub mod pagination;
+pub mod record;
+mod repository;
+pub mod row;
+pub mod schema;
+pub mod serde_address;
+pub mod signed;
+pub mod soft_delete;
+pub mod sql;
+pub mod table;
+#[cfg(feature = "trace-codec")]
+mod trace;
+pub mod tx;
+pub mod uint;
+
+pub use address::{
+    AddressText, BindHex, BitAddress, ChecksumAddress, FlexAddress, InvalidAddress, bind_validated,
+    join_address, split_address,
+};
+pub use array::{AddressArray, B256Array, PgBytesArray, filter_by_addresses_any};
+pub use bloom::bloom_contains;
+pub use bytes::{BytesHex, decode_bytes_ref};
+pub use contract::{derive_create2_address, derive_create_address};
+pub use copy::copy_in_addresses;
+pub use ddl::{EthColumns, fixed_bytes_check, lower_address_index, numeric_precision_for_bits};
+pub use diagnostics::{BinaryEncoded, describe_encoding};
+pub use dialect::{Backend, Dialect};
+#[cfg(feature = "time")]
+pub use events::EthEvent;
+pub use events::insert_events;
+pub use executor::{
+    FindResult, PrimitiveExecutor, find_address, find_address_result, get_optional_address,
+    typed_stream,
+};
+pub use export::export_addresses_csv;
+pub use fixed::{ColumnWidthMismatch, FixedBytesLe, FixedColumn};
+#[cfg(feature = "hmac")]
+pub use hmac_address::{HmacAddress, HmacKey, HmacMismatch};
+pub use json::AddressBalanceMap;
+pub use key::{EthKey, get_by_key};
+pub use log::{LogColumns, LogDataJson, LogJson, row_to_log, row_to_log_with};
+pub use migrate::{migrate_address_text_to_binary, swap_address_columns};
+pub use numeric::{
+    U256_PADDED_LEN, U256AsBigInt, U256Decimal, U256HiLo, U256Numeric, U256PaddedText,
+};
+#[cfg(feature = "bigdecimal")]
+pub use numeric::{bigdecimal_to_u256, u256_to_bigdecimal};
+pub use packed::{
+    PackedU8, PackedU16, PackedU24, PackedU32, PackedU40, PackedU48, PackedU56, PackedU64, PackedUint,
+};
+pub use pagination::{
+    InvalidScanToken, ScanToken, address_shards, next_key, page_after, scan_from, scan_page,
+};
+pub use record::AddressRecord;
+pub use row::{RowError, fetch_all_lenient, try_get_primitive, try_get_split_address};
+pub use schema::{EthType, SchemaMismatch, recommended_address_column, verify_schema};
+pub use serde_address::{checksum as serde_address_checksum, lower as serde_address_lower};
+pub use signed::{I256Blob, i256_blob_cmp, i256_blob_range};
+pub use soft_delete::{MaybeDeletedAddress, active_addresses};
+pub use sql::{
+    PrimitiveColumn, SortDir, UnknownSortKey, bind_address_cast, in_clause_addresses, lower_address_eq,
+    order_by,
+};
+pub use table::{
+    BalanceError, UpsertOutcome, address_exists, adjust_balance, count_by_first_byte,
+    count_distinct_addresses, delete_addresses, fetch_balances, get_or_insert_address, max_u256, sum_u256, upsert_address,
+};
+pub use tx::TxKindAddress;
+pub use uint::{SmallU256, U96Blob, U512Blob, UintBlob};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloy_primitives;
+    pub use sqlx;
+}
@@ -0,0 +1,50 @@
+//! Mapping alloy logs to and from database rows.
+
+use alloy_primitives::{Address, B256, Bytes, Log, LogData};
+use sqlx::{Row, postgres::PgRow, types::Json};
+
+use crate::{array::B256Array, bytes::decode_bytes_ref};
+
+/// [`LogData`] stored as a JSON document (`JSONB` on Postgres, `JSON` on
+/// MySQL, `TEXT` on SQLite).
+///
+/// Topics serialize as an array of `0x`-prefixed hashes and data as a single
+/// `0x`-prefixed hex string, using the alloy-primitives serde impls.
+pub type LogDataJson = Json<LogData>;
+
+/// A full [`Log`] (emitter address plus topics and data) stored as JSON.
+pub type LogJson = Json<Log>;
+
+/// Column names read by [`row_to_log_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LogColumns<'a> {
+    /// Emitter address, `BYTEA`.
+    pub address: &'a str,
+    /// Topic hashes, `BYTEA[]`.
+    pub topics: &'a str,
+    /// Log payload, `BYTEA`.
+    pub data: &'a str,
+}
+
+impl Default for LogColumns<'_> {
+    fn default() -> Self {
+        Self { address: "address", topics: "topics", data: "data" }
+    }
+}
+
+/// Converts a Postgres row with `address`, `topics` and `data` columns into
+/// the parts of an alloy log.
+pub fn row_to_log(row: &PgRow) -> Result<(Address, Vec<B256>, Bytes), sqlx::Error> {
+    row_to_log_with(row, &LogColumns::default())
+}
+
+/// Like [`row_to_log`], reading the columns named in `columns`.
+pub fn row_to_log_with(
+    row: &PgRow,
+    columns: &LogColumns<'_>,
+) -> Result<(Address, Vec<B256>, Bytes), sqlx::Error> {
+    let address: Address = row.try_get(columns.address)?;
+    let B256Array(topics) = row.try_get(columns.topics)?;
+    let data = Bytes::copy_from_slice(decode_bytes_ref(row, columns.data)?);
+    Ok((address, topics, data))
+}
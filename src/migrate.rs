@@ -0,0 +1,121 @@
+//! In-place migration of text address columns to the binary codec.
+//!
+//! The migration is split in two steps so the application can switch reads
+//! over in between: [`migrate_address_text_to_binary`] fills a `{column}_bin`
+//! column next to the text one, and [`swap_address_columns`] drops the text
+//! column and renames the binary one into its place. Table and column names
+//! are interpolated into the SQL and must come from trusted code.
+
+use alloy_primitives::Address;
+use sqlx::{ColumnIndex, Database, Decode, Encode, Executor, IntoArguments, Pool, Type};
+
+use crate::{
+    address::parse_address_str,
+    dialect::{Backend, Dialect},
+};
+
+/// Number of distinct text values converted per transaction.
+const CHUNK_SIZE: usize = 1000;
+
+/// Fills `{column}_bin` with the binary form of each text address in `column`.
+///
+/// Adds the binary column if it doesn't exist yet, then converts rows whose
+/// binary column is still `NULL`, [`CHUNK_SIZE`] distinct values per
+/// transaction, so only one chunk is held in memory and an interrupted run
+/// picks up where it stopped. `NULL` text is left alone. Returns the number of
+/// rows updated; text that isn't a valid address fails the chunk with
+/// [`sqlx::Error::Decode`].
+pub async fn migrate_address_text_to_binary<DB>(
+    pool: &Pool<DB>,
+    table: &str,
+    column: &str,
+) -> Result<u64, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'c> &'c mut <DB as Database>::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    Address: Type<DB>,
+    for<'q> String: Encode<'q, DB>,
+    String: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let dialect = DB::DIALECT;
+    let binary = format!("{column}_bin");
+    let (p1, p2) = (dialect.placeholder(1), dialect.placeholder(2));
+
+    let has_binary = sqlx::query(&format!("SELECT {binary} FROM {table} WHERE 1 = 0"))
+        .execute(pool)
+        .await
+        .is_ok();
+    if !has_binary {
+        sqlx::query(&format!(
+            "ALTER TABLE {table} ADD COLUMN {binary} {}",
+            dialect.binary_type(20)
+        ))
+        .execute(pool)
+        .await?;
+    }
+
+    let select = format!(
+        "SELECT DISTINCT {column} FROM {table} \
+         WHERE {column} IS NOT NULL AND {binary} IS NULL LIMIT {CHUNK_SIZE}"
+    );
+    let update = format!("UPDATE {table} SET {binary} = {p1} WHERE {column} = {p2}");
+    let mut migrated = 0;
+    loop {
+        let mut tx = pool.begin().await?;
+        let texts: Vec<String> = sqlx::query_scalar(&select).fetch_all(&mut *tx).await?;
+        if texts.is_empty() {
+            break;
+        }
+        for text in texts {
+            let address = parse_address_str(&text).map_err(sqlx::Error::Decode)?;
+            let result = sqlx::query(&update)
+                .bind(address)
+                .bind(text)
+                .execute(&mut *tx)
+                .await?;
+            migrated += DB::rows_affected(&result);
+        }
+        tx.commit().await?;
+    }
+    Ok(migrated)
+}
+
+/// Replaces the text `column` with the `{column}_bin` column filled by
+/// [`migrate_address_text_to_binary`].
+///
+/// Drops the text column and renames the binary one, so indexes and
+/// constraints on the text column are dropped with it and must be recreated.
+/// Postgres and SQLite run both steps in one transaction. MySQL commits
+/// implicitly after every `ALTER TABLE`, so there the swap is a single
+/// `ALTER TABLE ... DROP COLUMN ..., RENAME COLUMN ...` statement, which the
+/// server applies atomically. Needs MySQL 8.0 or SQLite 3.35 for
+/// `RENAME COLUMN`/`DROP COLUMN`.
+pub async fn swap_address_columns<DB>(pool: &Pool<DB>, table: &str, column: &str) -> Result<(), sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'c> &'c mut <DB as Database>::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+{
+    let drop = format!("DROP COLUMN {column}");
+    let rename = format!("RENAME COLUMN {column}_bin TO {column}");
+    if DB::DIALECT == Dialect::MySql {
+        sqlx::query(&format!("ALTER TABLE {table} {drop}, {rename}"))
+            .execute(pool)
+            .await?;
+        return Ok(());
+    }
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(&format!("ALTER TABLE {table} {drop}"))
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(&format!("ALTER TABLE {table} {rename}"))
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await
+}
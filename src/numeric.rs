@@ -0,0 +1,431 @@
+//! Decimal representations of `U256`.
+//!
+//! alloy-primitives stores `U256` as a 32-byte big-endian blob. Schemas that
+//! need database-side arithmetic use Postgres `NUMERIC(78, 0)` instead, and
+//! backends without a wide numeric type (SQLite) fall back to decimal text.
+
+use alloy_primitives::U256;
+use sqlx::{
+    Database, Decode, Encode, FromRow, Postgres, Row, Type, TypeInfo, ValueRef,
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{PgArgumentBuffer, PgArguments, PgRow, PgTypeInfo, PgValueFormat, PgValueRef},
+    query::Query,
+};
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NBASE: u64 = 10_000;
+
+/// A [`U256`] stored as a Postgres `NUMERIC`.
+///
+/// Decoding accepts a zero fractional part (`123.0`, as written by tools that
+/// don't constrain the scale) and rejects anything else that isn't a
+/// non-negative integer fitting in 256 bits. It also reads `TEXT`/`VARCHAR`
+/// columns holding decimal strings, as many indexers write them; the
+/// column's type picks the parse path. Encoding always produces `NUMERIC`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256Numeric(pub U256);
+
+impl From<U256> for U256Numeric {
+    fn from(value: U256) -> Self {
+        Self(value)
+    }
+}
+
+impl From<U256Numeric> for U256 {
+    fn from(value: U256Numeric) -> Self {
+        value.0
+    }
+}
+
+/// A [`U256`] stored as a decimal string in a `TEXT` column.
+///
+/// This is the portable option for SQLite, which has no numeric type wide
+/// enough for 256 bits. Decoding rejects anything that isn't a non-negative
+/// integer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256Decimal(pub U256);
+
+impl From<U256> for U256Decimal {
+    fn from(value: U256) -> Self {
+        Self(value)
+    }
+}
+
+impl From<U256Decimal> for U256 {
+    fn from(value: U256Decimal) -> Self {
+        value.0
+    }
+}
+
+impl<DB: Database> Type<DB> for U256Decimal
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for U256Decimal
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let text = self.0.to_string();
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("text", text.len());
+        <String as Encode<'q, DB>>::encode(text, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for U256Decimal
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let text = <&'r str as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, text.len());
+        parse_decimal_u256(text).map(Self)
+    }
+}
+
+/// Width of [`U256PaddedText`]: the number of digits in `U256::MAX`.
+pub const U256_PADDED_LEN: usize = 78;
+
+/// A [`U256`] stored as a zero-padded, fixed-width decimal string.
+///
+/// Every value is written as exactly [`U256_PADDED_LEN`] digits, so text
+/// comparison, and with it `ORDER BY` and range predicates on the column,
+/// matches numeric order (on Postgres, given a byte-wise collation such as
+/// `COLLATE "C"`). Decoding also accepts unpadded decimal text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256PaddedText(pub U256);
+
+impl From<U256> for U256PaddedText {
+    fn from(value: U256) -> Self {
+        Self(value)
+    }
+}
+
+impl From<U256PaddedText> for U256 {
+    fn from(value: U256PaddedText) -> Self {
+        value.0
+    }
+}
+
+impl<DB: Database> Type<DB> for U256PaddedText
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for U256PaddedText
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let text = format!("{:0>width$}", self.0.to_string(), width = U256_PADDED_LEN);
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("text", text.len());
+        <String as Encode<'q, DB>>::encode(text, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for U256PaddedText
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let text = <&'r str as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, text.len());
+        parse_decimal_u256(text).map(Self)
+    }
+}
+
+/// A [`U256`] stored in a `BIGINT` column known to hold small values.
+///
+/// Encoding fails unless the value fits in an `i64`, and decoding rejects
+/// negative integers, so the column stays a plain 8-byte integer that the
+/// database can index and compute with natively.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U256AsBigInt(pub U256);
+
+impl From<U256> for U256AsBigInt {
+    fn from(value: U256) -> Self {
+        Self(value)
+    }
+}
+
+impl From<U256AsBigInt> for U256 {
+    fn from(value: U256AsBigInt) -> Self {
+        value.0
+    }
+}
+
+impl<DB: Database> Type<DB> for U256AsBigInt
+where
+    i64: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i64 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i64 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for U256AsBigInt
+where
+    i64: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let value = i64::try_from(self.0)
+            .map_err(|_| format!("cannot encode U256 {} as BIGINT: out of range", self.0))?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("int", size_of::<i64>());
+        <i64 as Encode<'q, DB>>::encode(value, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for U256AsBigInt
+where
+    i64: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let value = <i64 as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, size_of::<i64>());
+        u64::try_from(value)
+            .map(|v| Self(U256::from(v)))
+            .map_err(|_| format!("cannot decode BIGINT {value} as U256: negative").into())
+    }
+}
+
+/// Parses a non-negative decimal string, allowing only zeros after a `.`.
+pub(crate) fn parse_decimal_u256(s: &str) -> Result<U256, BoxDynError> {
+    let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if !fraction.bytes().all(|b| b == b'0') {
+        return Err(format!("cannot decode {s:?} as U256: non-zero fractional part").into());
+    }
+    if integer.is_empty() || !integer.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("cannot decode {s:?} as U256: not a non-negative integer").into());
+    }
+    U256::from_str_radix(integer, 10)
+        .map_err(|e| format!("cannot decode {s:?} as U256: {e}").into())
+}
+
+/// Decodes Postgres' binary `NUMERIC` representation.
+fn decode_numeric_binary(bytes: &[u8]) -> Result<U256, BoxDynError> {
+    let read_u16 = |at: usize| -> Result<u16, BoxDynError> {
+        bytes
+            .get(at..at + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .ok_or_else(|| "truncated NUMERIC value".into())
+    };
+    let ndigits = read_u16(0)? as usize;
+    let weight = read_u16(2)? as i16;
+    let sign = read_u16(4)?;
+    let digits = (0..ndigits)
+        .map(|i| read_u16(8 + 2 * i))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match sign {
+        NUMERIC_POS => {}
+        NUMERIC_NEG if digits.iter().all(|&d| d == 0) => {}
+        NUMERIC_NEG => return Err("cannot decode a negative NUMERIC as U256".into()),
+        _ => return Err("cannot decode NaN or infinite NUMERIC as U256".into()),
+    }
+
+    // Digit `i` is worth `NBASE^(weight - i)`; the ones past `weight` are the fraction.
+    let integer_groups = usize::try_from(i32::from(weight) + 1).unwrap_or(0);
+    if digits.iter().skip(integer_groups).any(|&d| d != 0) {
+        return Err("cannot decode NUMERIC as U256: non-zero fractional part".into());
+    }
+    let mut value = U256::ZERO;
+    for i in 0..integer_groups {
+        let digit = digits.get(i).copied().unwrap_or(0);
+        value = value
+            .checked_mul(U256::from(NBASE))
+            .and_then(|v| v.checked_add(U256::from(digit)))
+            .ok_or("NUMERIC value overflows U256")?;
+    }
+    Ok(value)
+}
+
+/// Encodes `value` in Postgres' binary `NUMERIC` representation.
+fn encode_numeric_binary(value: U256, buf: &mut Vec<u8>) {
+    let mut digits = Vec::new();
+    let mut rest = value;
+    while !rest.is_zero() {
+        let (quotient, remainder) = rest.div_rem(U256::from(NBASE));
+        digits.push(remainder.to::<u16>());
+        rest = quotient;
+    }
+    digits.reverse();
+    let weight = digits.len().saturating_sub(1) as i16;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    buf.extend_from_slice(&(digits.len() as u16).to_be_bytes());
+    buf.extend_from_slice(&weight.to_be_bytes());
+    buf.extend_from_slice(&NUMERIC_POS.to_be_bytes());
+    buf.extend_from_slice(&0u16.to_be_bytes());
+    for digit in digits {
+        buf.extend_from_slice(&digit.to_be_bytes());
+    }
+}
+
+impl Type<Postgres> for U256Numeric {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("numeric")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        ty.name().eq_ignore_ascii_case("numeric") || <String as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for U256Numeric {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let start = buf.len();
+        encode_numeric_binary(self.0, buf);
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("numeric", buf.len() - start);
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for U256Numeric {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        if <String as Type<Postgres>>::compatible(&value.type_info()) {
+            let text = <&str as Decode<'r, Postgres>>::decode(value)?;
+            #[cfg(feature = "trace-codec")]
+            crate::trace::decoded::<Self>(&column_type, text.len());
+            return parse_decimal_u256(text).map(Self);
+        }
+        match value.format() {
+            PgValueFormat::Binary => {
+                let bytes = value.as_bytes()?;
+                #[cfg(feature = "trace-codec")]
+                crate::trace::decoded::<Self>(&column_type, bytes.len());
+                decode_numeric_binary(bytes).map(Self)
+            }
+            PgValueFormat::Text => {
+                let text = value.as_str()?;
+                #[cfg(feature = "trace-codec")]
+                crate::trace::decoded::<Self>(&column_type, text.len());
+                parse_decimal_u256(text).map(Self)
+            }
+        }
+    }
+}
+
+/// A [`U256`] split into two 128-bit halves, for warehouse schemas that keep
+/// `amount_hi` and `amount_lo` as separate `NUMERIC(39, 0)` columns.
+///
+/// Implements [`FromRow`] over those two column names, so it can be fetched
+/// with `query_as` or embedded in a larger struct with `#[sqlx(flatten)]`.
+/// Use [`U256HiLo::bind`] to write both halves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct U256HiLo {
+    pub hi: u128,
+    pub lo: u128,
+}
+
+impl From<U256> for U256HiLo {
+    fn from(value: U256) -> Self {
+        let limbs = value.as_limbs();
+        Self {
+            hi: u128::from(limbs[3]) << 64 | u128::from(limbs[2]),
+            lo: u128::from(limbs[1]) << 64 | u128::from(limbs[0]),
+        }
+    }
+}
+
+impl From<U256HiLo> for U256 {
+    fn from(value: U256HiLo) -> Self {
+        U256::from(value.hi) << 128 | U256::from(value.lo)
+    }
+}
+
+impl U256HiLo {
+    /// Binds `hi` then `lo` as the next two parameters of `query`.
+    pub fn bind<'q>(
+        self,
+        query: Query<'q, Postgres, PgArguments>,
+    ) -> Query<'q, Postgres, PgArguments> {
+        query.bind(U256Numeric(U256::from(self.hi))).bind(U256Numeric(U256::from(self.lo)))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for U256HiLo {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let half = |col: &str| -> Result<u128, sqlx::Error> {
+            let U256Numeric(value) = row.try_get(col)?;
+            u128::try_from(value).map_err(|_| sqlx::Error::ColumnDecode {
+                index: format!("{col:?}"),
+                source: format!("{value} does not fit in 128 bits").into(),
+            })
+        };
+        Ok(Self { hi: half("amount_hi")?, lo: half("amount_lo")? })
+    }
+}
+
+/// Converts a [`U256`] to a [`BigDecimal`](sqlx::types::BigDecimal) with scale 0.
+#[cfg(feature = "bigdecimal")]
+pub fn u256_to_bigdecimal(value: U256) -> sqlx::types::BigDecimal {
+    use std::str::FromStr;
+
+    sqlx::types::BigDecimal::from_str(&value.to_string())
+        .expect("a U256 decimal string is a valid BigDecimal")
+}
+
+/// Converts a [`BigDecimal`](sqlx::types::BigDecimal) to a [`U256`].
+///
+/// Fails on fractional, negative, or out-of-range values. Integral values
+/// written with a scale (`42.000`) are accepted.
+#[cfg(feature = "bigdecimal")]
+pub fn bigdecimal_to_u256(value: &sqlx::types::BigDecimal) -> Result<U256, BoxDynError> {
+    if !value.is_integer() {
+        return Err(format!("cannot convert {value} to U256: non-zero fractional part").into());
+    }
+    // Scale 0 makes the unscaled integer the value itself, and sidesteps
+    // BigDecimal's exponent notation in `Display`.
+    let (integer, _) = value.with_scale(0).into_bigint_and_exponent();
+    parse_decimal_u256(&integer.to_string())
+}
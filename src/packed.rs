@@ -0,0 +1,202 @@
+//! Odd-width unsigned integers in native integer columns.
+//!
+//! ruint stores every `Uint` as a big-endian blob. Packed Solidity fields
+//! (`uint24`, `uint48`, ...) usually fit a native integer, which keeps them
+//! readable and lets the database do arithmetic. [`PackedUint`] maps widths
+//! up to 64 bits to the smallest integer column that holds them:
+//!
+//! - Postgres: `SMALLINT` below 16 bits, `INTEGER` below 32, else `BIGINT`.
+//!   Postgres has no unsigned types, so a 64-bit value above `i64::MAX`
+//!   fails to encode.
+//! - MySQL: the smallest `UNSIGNED` type of at least `BITS` bits, e.g.
+//!   `TINYINT UNSIGNED` for `uint8` and `BIGINT UNSIGNED` for `uint40`..`uint64`.
+//!   `uint24` binds as `INT UNSIGNED` and reads from `MEDIUMINT UNSIGNED`.
+//! - SQLite: `INTEGER`, with the same `i64::MAX` limit as Postgres.
+//!
+//! Decoding accepts any integer column and range-checks the value against
+//! `BITS`, so a column wider than the field can't silently truncate.
+
+use alloy_primitives::Uint;
+use sqlx::{
+    Decode, Encode, MySql, Postgres, Sqlite, Type, ValueRef,
+    encode::IsNull,
+    error::BoxDynError,
+    mysql::{MySqlTypeInfo, MySqlValueRef},
+    postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef},
+    sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
+};
+
+/// A [`Uint`] of at most 64 bits stored in a native integer column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedUint<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
+
+pub type PackedU8 = PackedUint<8, 1>;
+pub type PackedU16 = PackedUint<16, 1>;
+pub type PackedU24 = PackedUint<24, 1>;
+pub type PackedU32 = PackedUint<32, 1>;
+pub type PackedU40 = PackedUint<40, 1>;
+pub type PackedU48 = PackedUint<48, 1>;
+pub type PackedU56 = PackedUint<56, 1>;
+pub type PackedU64 = PackedUint<64, 1>;
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for PackedUint<BITS, LIMBS> {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<PackedUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    fn from(value: PackedUint<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PackedUint<BITS, LIMBS> {
+    fn to_u64(self) -> Result<u64, BoxDynError> {
+        u64::try_from(self.0)
+            .map_err(|_| format!("Uint<{BITS}> value {} does not fit a native integer", self.0).into())
+    }
+
+    fn to_i64(self) -> Result<i64, BoxDynError> {
+        i64::try_from(self.to_u64()?)
+            .map_err(|_| format!("Uint<{BITS}> value {} does not fit a signed BIGINT", self.0).into())
+    }
+
+    fn from_i64(value: i64) -> Result<Self, BoxDynError> {
+        Uint::try_from(value)
+            .map(Self)
+            .map_err(|_| format!("integer {value} is out of range for Uint<{BITS}>").into())
+    }
+
+    fn from_u64(value: u64) -> Result<Self, BoxDynError> {
+        Uint::try_from(value)
+            .map(Self)
+            .map_err(|_| format!("integer {value} is out of range for Uint<{BITS}>").into())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Type<Postgres> for PackedUint<BITS, LIMBS> {
+    fn type_info() -> PgTypeInfo {
+        match BITS {
+            0..=15 => <i16 as Type<Postgres>>::type_info(),
+            16..=31 => <i32 as Type<Postgres>>::type_info(),
+            _ => <i64 as Type<Postgres>>::type_info(),
+        }
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <i16 as Type<Postgres>>::compatible(ty)
+            || <i32 as Type<Postgres>>::compatible(ty)
+            || <i64 as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Encode<'_, Postgres> for PackedUint<BITS, LIMBS> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let value = self.to_i64()?;
+        #[cfg(feature = "trace-codec")]
+        let start = buf.len();
+        // The range check above plus `BITS` guarantee the narrowing is lossless
+        let is_null = match BITS {
+            0..=15 => <i16 as Encode<'_, Postgres>>::encode(value as i16, buf),
+            16..=31 => <i32 as Encode<'_, Postgres>>::encode(value as i32, buf),
+            _ => <i64 as Encode<'_, Postgres>>::encode(value, buf),
+        }?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("int", buf.len() - start);
+        Ok(is_null)
+    }
+}
+
+impl<'r, const BITS: usize, const LIMBS: usize> Decode<'r, Postgres> for PackedUint<BITS, LIMBS> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(
+            &crate::trace::column_type(&value),
+            value.as_bytes().map_or(0, <[u8]>::len),
+        );
+        let ty = value.type_info().into_owned();
+        let value = if <i16 as Type<Postgres>>::compatible(&ty) {
+            i64::from(<i16 as Decode<'r, Postgres>>::decode(value)?)
+        } else if <i32 as Type<Postgres>>::compatible(&ty) {
+            i64::from(<i32 as Decode<'r, Postgres>>::decode(value)?)
+        } else {
+            <i64 as Decode<'r, Postgres>>::decode(value)?
+        };
+        Self::from_i64(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Type<MySql> for PackedUint<BITS, LIMBS> {
+    fn type_info() -> MySqlTypeInfo {
+        match BITS {
+            0..=8 => <u8 as Type<MySql>>::type_info(),
+            9..=16 => <u16 as Type<MySql>>::type_info(),
+            17..=32 => <u32 as Type<MySql>>::type_info(),
+            _ => <u64 as Type<MySql>>::type_info(),
+        }
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <u64 as Type<MySql>>::compatible(ty) || <i64 as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Encode<'_, MySql> for PackedUint<BITS, LIMBS> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let value = self.to_u64()?;
+        #[cfg(feature = "trace-codec")]
+        let start = buf.len();
+        let is_null = match BITS {
+            0..=8 => <u8 as Encode<'_, MySql>>::encode(value as u8, buf),
+            9..=16 => <u16 as Encode<'_, MySql>>::encode(value as u16, buf),
+            17..=32 => <u32 as Encode<'_, MySql>>::encode(value as u32, buf),
+            _ => <u64 as Encode<'_, MySql>>::encode(value, buf),
+        }?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("int", buf.len() - start);
+        Ok(is_null)
+    }
+}
+
+impl<'r, const BITS: usize, const LIMBS: usize> Decode<'r, MySql> for PackedUint<BITS, LIMBS> {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&crate::trace::column_type(&value), size_of::<u64>());
+        if <u64 as Type<MySql>>::compatible(&value.type_info()) {
+            Self::from_u64(<u64 as Decode<'r, MySql>>::decode(value)?)
+        } else {
+            Self::from_i64(<i64 as Decode<'r, MySql>>::decode(value)?)
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Type<Sqlite> for PackedUint<BITS, LIMBS> {
+    fn type_info() -> SqliteTypeInfo {
+        <i64 as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <i64 as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q, const BITS: usize, const LIMBS: usize> Encode<'q, Sqlite> for PackedUint<BITS, LIMBS> {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        let value = self.to_i64()?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("int", size_of::<i64>());
+        <i64 as Encode<'q, Sqlite>>::encode(value, buf)
+    }
+}
+
+impl<'r, const BITS: usize, const LIMBS: usize> Decode<'r, Sqlite> for PackedUint<BITS, LIMBS> {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let value = <i64 as Decode<'r, Sqlite>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, size_of::<i64>());
+        Self::from_i64(value)
+    }
+}
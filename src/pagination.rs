@@ -0,0 +1,212 @@
+//! Keyset scans and shard ranges over binary primitive keys.
+//!
+//! Table and column names are interpolated into the SQL and must come from
+//! trusted code, never from user input.
+
+use std::{fmt, str::FromStr};
+
+use alloy_primitives::{Address, B256, U256};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use sqlx::{
+    ColumnIndex, Database, Decode, Encode, Executor, FromRow, IntoArguments, PgPool, Pool, Row, Type,
+};
+
+use crate::dialect::Backend;
+
+/// Returns up to `limit` keys `>= start` from a Postgres `BYTEA` key column,
+/// in byte order.
+///
+/// `BYTEA` compares byte-wise, so the order matches [`B256`]'s `Ord`. To
+/// resume, pass [`next_key`] of the last key returned.
+pub async fn scan_from(
+    pool: &PgPool,
+    table: &str,
+    key_col: &str,
+    start: B256,
+    limit: u32,
+) -> Result<Vec<B256>, sqlx::Error> {
+    let sql = format!(
+        "SELECT {key_col} FROM {table} WHERE {key_col} >= $1 ORDER BY {key_col} LIMIT $2"
+    );
+    sqlx::query_scalar(&sql)
+        .bind(start)
+        .bind(i64::from(limit))
+        .fetch_all(pool)
+        .await
+}
+
+/// Returns the next page of up to `limit` addresses after the cursor `after`,
+/// ascending.
+///
+/// Pass `None` for the first page and the last address of the previous page
+/// afterwards; a page shorter than `limit` is the last one. Relies on the
+/// binary `address` column sorting like [`Address`]'s `Ord`.
+pub async fn page_after<DB>(
+    pool: &Pool<DB>,
+    table: &str,
+    after: Option<Address>,
+    limit: u32,
+) -> Result<Vec<Address>, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB>,
+    i64: Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let dialect = DB::DIALECT;
+    let limit = i64::from(limit);
+    match after {
+        Some(cursor) => {
+            let sql = format!(
+                "SELECT address FROM {table} WHERE address > {} ORDER BY address LIMIT {}",
+                dialect.placeholder(1),
+                dialect.placeholder(2),
+            );
+            sqlx::query_scalar(&sql).bind(cursor).bind(limit).fetch_all(pool).await
+        }
+        None => {
+            let sql = format!(
+                "SELECT address FROM {table} ORDER BY address LIMIT {}",
+                dialect.placeholder(1),
+            );
+            sqlx::query_scalar(&sql).bind(limit).fetch_all(pool).await
+        }
+    }
+}
+
+/// Resume point for [`scan_page`]: the `(id, address)` of the last row seen.
+///
+/// Its string form (via [`Display`](fmt::Display) and [`FromStr`]) is an
+/// opaque URL-safe base64 token, suitable for handing to API clients.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScanToken {
+    pub id: i64,
+    pub address: Address,
+}
+
+/// Length of a decoded [`ScanToken`]: a big-endian `i64` id and the address.
+const SCAN_TOKEN_LEN: usize = 8 + 20;
+
+/// A token string that isn't a [`ScanToken`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidScanToken(pub String);
+
+impl fmt::Display for InvalidScanToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid scan token {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidScanToken {}
+
+impl fmt::Display for ScanToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut raw = [0u8; SCAN_TOKEN_LEN];
+        raw[..8].copy_from_slice(&self.id.to_be_bytes());
+        raw[8..].copy_from_slice(self.address.as_slice());
+        f.write_str(&URL_SAFE_NO_PAD.encode(raw))
+    }
+}
+
+impl FromStr for ScanToken {
+    type Err = InvalidScanToken;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = URL_SAFE_NO_PAD.decode(s).map_err(|_| InvalidScanToken(s.to_string()))?;
+        let raw: [u8; SCAN_TOKEN_LEN] = raw.try_into().map_err(|_| InvalidScanToken(s.to_string()))?;
+        let (id, address) = raw.split_at(8);
+        Ok(Self {
+            id: i64::from_be_bytes(id.try_into().expect("split at 8")),
+            address: Address::from_slice(address),
+        })
+    }
+}
+
+/// Returns the next page of up to `limit` rows of `table` after `token`,
+/// ordered by `(id, address)`, together with the token for the page after.
+///
+/// Pass `None` for the first page. Every row is decoded as `T`; the table
+/// needs integer `id` and binary `address` columns, which `T` may or may not
+/// map. The returned token is `None` once a page comes back short, so a
+/// table whose size is a multiple of `limit` ends with one empty page.
+pub async fn scan_page<DB, T>(
+    pool: &Pool<DB>,
+    table: &str,
+    token: Option<ScanToken>,
+    limit: u32,
+) -> Result<(Vec<T>, Option<ScanToken>), sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    T: for<'r> FromRow<'r, DB::Row>,
+    for<'q> Address: Encode<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB>,
+    i64: for<'r> Decode<'r, DB> + Type<DB>,
+    for<'a> &'a str: ColumnIndex<DB::Row>,
+{
+    let dialect = DB::DIALECT;
+    let select = format!("SELECT {table}.*, {} AS scan_id FROM {table}", dialect.bigint("id"));
+    let rows = match token {
+        Some(token) => {
+            let sql = format!(
+                "{select} WHERE (id, address) > ({}, {}) ORDER BY id, address LIMIT {}",
+                dialect.placeholder(1),
+                dialect.placeholder(2),
+                dialect.placeholder(3),
+            );
+            sqlx::query(&sql)
+                .bind(token.id)
+                .bind(token.address)
+                .bind(i64::from(limit))
+                .fetch_all(pool)
+                .await?
+        }
+        None => {
+            let sql = format!("{select} ORDER BY id, address LIMIT {}", dialect.placeholder(1));
+            sqlx::query(&sql).bind(i64::from(limit)).fetch_all(pool).await?
+        }
+    };
+
+    let next = match rows.last() {
+        Some(last) if rows.len() == limit as usize => Some(ScanToken {
+            id: last.try_get("scan_id")?,
+            address: last.try_get("address")?,
+        }),
+        _ => None,
+    };
+    let items = rows.iter().map(T::from_row).collect::<Result<Vec<_>, _>>()?;
+    Ok((items, next))
+}
+
+/// Returns the key immediately after `key` in byte order, or `None` for the
+/// all-`0xff` key.
+pub fn next_key(key: B256) -> Option<B256> {
+    U256::from_be_bytes(key.0)
+        .checked_add(U256::from(1))
+        .map(|next| B256::from(next.to_be_bytes::<32>()))
+}
+
+/// Splits the 160-bit address space into `n` contiguous, half-open ranges.
+///
+/// Each shard is `(lo, hi)` for `address >= lo AND address < hi`; the last
+/// shard's `hi` is `None` (no upper bound), since the end of the space isn't
+/// an address. The ranges are as even as possible, the first starts at
+/// [`Address::ZERO`] and each `hi` is the next shard's `lo`, so querying
+/// every shard visits each row exactly once. Returns an empty vector for
+/// `n == 0`.
+pub fn address_shards(n: usize) -> Vec<(Address, Option<Address>)> {
+    let space = U256::from(1) << 160;
+    let count = U256::from(n);
+    let bound = |i: usize| space * U256::from(i) / count;
+    let to_address = |value: U256| Address::from_slice(&value.to_be_bytes::<32>()[12..]);
+
+    (0..n)
+        .map(|i| (to_address(bound(i)), (i + 1 < n).then(|| to_address(bound(i + 1)))))
+        .collect()
+}
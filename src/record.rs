@@ -0,0 +1,53 @@
+//! Postgres composite (`ROW`/record) values holding an address.
+//!
+//! Functions that return a composite such as `(address BYTEA, balance
+//! NUMERIC)` produce a single record column. [`AddressRecord`] destructures
+//! it with sqlx's record decoder, using the alloy-primitives codec for the
+//! address field.
+
+use alloy_primitives::Address;
+use sqlx::{
+    Decode, Postgres, Type, TypeInfo,
+    error::BoxDynError,
+    postgres::{PgTypeInfo, PgTypeKind, PgValueRef, types::PgRecordDecoder},
+};
+
+/// A Postgres record whose first field is a `BYTEA` address and whose second
+/// field decodes as `T`, e.g. `AddressRecord<U256Numeric>` for
+/// `(address, balance)`.
+///
+/// Accepts anonymous records (`ROW($1, 42)`) and named composite types.
+/// Fields after the second are ignored. Decode only: bind the fields
+/// individually when writing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AddressRecord<T> {
+    pub address: Address,
+    pub value: T,
+}
+
+impl<T> Type<Postgres> for AddressRecord<T> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("record")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        ty.name().eq_ignore_ascii_case("record") || matches!(ty.kind(), PgTypeKind::Composite(_))
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for AddressRecord<T>
+where
+    T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(
+            &crate::trace::column_type(&value),
+            value.as_bytes().map_or(0, <[u8]>::len),
+        );
+        let mut decoder = PgRecordDecoder::new(value)?;
+        let address = decoder.try_decode::<Address>()?;
+        let value = decoder.try_decode::<T>()?;
+        Ok(Self { address, value })
+    }
+}
@@ -0,0 +1,123 @@
+//! The [`eth_repository!`](crate::eth_repository) macro.
+
+/// Generates a repository struct for a table keyed by a binary `address`
+/// column.
+///
+/// The row type must implement `FromRow` and have an `address: Address`
+/// field. The listed fields are the remaining columns written by `insert`;
+/// `get_by_address` and `list` select `*`, so columns the database fills in
+/// (an auto-increment `id`, defaults) can still be read back. The repository
+/// is generic over the backend and picks the placeholder style from
+/// [`Backend`](crate::Backend). The table name is interpolated into the SQL
+/// and must come from trusted code.
+///
+/// ```ignore
+/// eth_repository! {
+///     pub struct UserRepo for UserInfo in "users" {
+///         name: String,
+///     }
+/// }
+///
+/// let repo = UserRepo::new(pool);
+/// repo.insert(&user).await?;
+/// let user = repo.get_by_address(user.address).await?;
+/// ```
+///
+/// Generated methods:
+///
+/// - `new(pool)` and `pool()`
+/// - `insert(&row)`
+/// - `get_by_address(address) -> Option<Row>`
+/// - `delete_by_address(address) -> bool`, whether a row was deleted
+/// - `list() -> Vec<Row>`, ordered by address
+#[macro_export]
+macro_rules! eth_repository {
+    (
+        $vis:vis struct $repo:ident for $row:ty in $table:literal {
+            $($field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $vis struct $repo<DB: $crate::Backend> {
+            pool: $crate::__private::sqlx::Pool<DB>,
+        }
+
+        impl<DB> $repo<DB>
+        where
+            DB: $crate::Backend,
+            for<'c> &'c $crate::__private::sqlx::Pool<DB>:
+                $crate::__private::sqlx::Executor<'c, Database = DB>,
+            for<'q> <DB as $crate::__private::sqlx::Database>::Arguments<'q>:
+                $crate::__private::sqlx::IntoArguments<'q, DB>,
+            for<'q> $crate::__private::alloy_primitives::Address:
+                $crate::__private::sqlx::Encode<'q, DB>,
+            $crate::__private::alloy_primitives::Address: $crate::__private::sqlx::Type<DB>,
+            $(
+                for<'q> $ty: $crate::__private::sqlx::Encode<'q, DB>,
+                $ty: $crate::__private::sqlx::Type<DB>,
+            )*
+            for<'r> $row: $crate::__private::sqlx::FromRow<'r, <DB as $crate::__private::sqlx::Database>::Row>,
+            $row: Send + Unpin,
+        {
+            pub const TABLE: &'static str = $table;
+
+            pub fn new(pool: $crate::__private::sqlx::Pool<DB>) -> Self {
+                Self { pool }
+            }
+
+            pub fn pool(&self) -> &$crate::__private::sqlx::Pool<DB> {
+                &self.pool
+            }
+
+            pub async fn insert(&self, row: &$row) -> Result<(), $crate::__private::sqlx::Error> {
+                let dialect = <DB as $crate::Backend>::DIALECT;
+                let columns: &[&str] = &["address" $(, stringify!($field))*];
+                let placeholders = (1..=columns.len())
+                    .map(|n| dialect.placeholder(n))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let sql = format!(
+                    "INSERT INTO {} ({}) VALUES ({})",
+                    $table,
+                    columns.join(", "),
+                    placeholders,
+                );
+                $crate::__private::sqlx::query(&sql)
+                    .bind(&row.address)
+                    $(.bind(&row.$field))*
+                    .execute(&self.pool)
+                    .await?;
+                Ok(())
+            }
+
+            pub async fn get_by_address(
+                &self,
+                address: $crate::__private::alloy_primitives::Address,
+            ) -> Result<Option<$row>, $crate::__private::sqlx::Error> {
+                let dialect = <DB as $crate::Backend>::DIALECT;
+                let sql = format!("SELECT * FROM {} WHERE address = {}", $table, dialect.placeholder(1));
+                $crate::__private::sqlx::query_as(&sql)
+                    .bind(address)
+                    .fetch_optional(&self.pool)
+                    .await
+            }
+
+            pub async fn delete_by_address(
+                &self,
+                address: $crate::__private::alloy_primitives::Address,
+            ) -> Result<bool, $crate::__private::sqlx::Error> {
+                let dialect = <DB as $crate::Backend>::DIALECT;
+                let sql = format!("DELETE FROM {} WHERE address = {}", $table, dialect.placeholder(1));
+                let result = $crate::__private::sqlx::query(&sql)
+                    .bind(address)
+                    .execute(&self.pool)
+                    .await?;
+                Ok(<DB as $crate::Backend>::rows_affected(&result) > 0)
+            }
+
+            pub async fn list(&self) -> Result<Vec<$row>, $crate::__private::sqlx::Error> {
+                let sql = format!("SELECT * FROM {} ORDER BY address", $table);
+                $crate::__private::sqlx::query_as(&sql).fetch_all(&self.pool).await
+            }
+        }
+    };
+}
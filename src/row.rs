@@ -0,0 +1,111 @@
+//! Row accessors with more helpful errors.
+
+use std::{any::type_name, fmt};
+
+use alloy_primitives::Address;
+use sqlx::{
+    ColumnIndex, Database, Decode, Executor, FromRow, IntoArguments, Row, Type, query::Query,
+};
+
+/// Like [`Row::try_get`], but a decode error also names the Rust type the
+/// column was being decoded into.
+///
+/// Handy when a `FromRow` struct starts failing after a schema change and the
+/// driver error alone doesn't say which primitive column drifted. Other
+/// errors, such as [`sqlx::Error::ColumnNotFound`], are returned unchanged.
+pub fn try_get_primitive<'r, R, T>(row: &'r R, col: &str) -> Result<T, sqlx::Error>
+where
+    R: Row,
+    T: Decode<'r, R::Database> + Type<R::Database>,
+    for<'a> &'a str: ColumnIndex<R>,
+{
+    row.try_get(col).map_err(|err| match err {
+        sqlx::Error::ColumnDecode { index, source } => sqlx::Error::ColumnDecode {
+            index,
+            source: format!("cannot decode as `{}`: {source}", type_name::<T>()).into(),
+        },
+        err => err,
+    })
+}
+
+/// Reads an address stored across a `BIGINT` prefix column and a 12-byte
+/// binary suffix column, as produced by
+/// [`split_address`](crate::address::split_address).
+///
+/// Use it from a hand-written `FromRow` impl:
+///
+/// ```ignore
+/// impl<'r> FromRow<'r, PgRow> for Holder {
+///     fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+///         Ok(Self {
+///             address: try_get_split_address(row, "addr_prefix", "addr_suffix")?,
+///             balance: row.try_get("balance")?,
+///         })
+///     }
+/// }
+/// ```
+pub fn try_get_split_address<'r, R>(
+    row: &'r R,
+    prefix_col: &str,
+    suffix_col: &str,
+) -> Result<Address, sqlx::Error>
+where
+    R: Row,
+    i64: Decode<'r, R::Database> + Type<R::Database>,
+    &'r [u8]: Decode<'r, R::Database> + Type<R::Database>,
+    for<'a> &'a str: ColumnIndex<R>,
+{
+    let prefix: i64 = row.try_get(prefix_col)?;
+    let suffix: &[u8] = row.try_get(suffix_col)?;
+    crate::address::join_address(prefix, suffix).map_err(|source| sqlx::Error::ColumnDecode {
+        index: format!("{suffix_col:?}"),
+        source,
+    })
+}
+
+/// A row that [`fetch_all_lenient`] could not decode.
+#[derive(Debug)]
+pub struct RowError {
+    /// Position of the row in the result set.
+    pub index: usize,
+    pub error: sqlx::Error,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}: {}", self.index, self.error)
+    }
+}
+
+impl std::error::Error for RowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Runs `query` and decodes every row, keeping going past rows that fail.
+///
+/// Returns the decoded rows and the per-row errors separately, both in
+/// result-set order. Only a failure of the query itself is returned as `Err`.
+pub async fn fetch_all_lenient<'q, 'c, E, DB, T>(
+    executor: E,
+    query: Query<'q, DB, <DB as Database>::Arguments<'q>>,
+) -> Result<(Vec<T>, Vec<RowError>), sqlx::Error>
+where
+    DB: Database,
+    E: Executor<'c, Database = DB>,
+    <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    T: for<'r> FromRow<'r, DB::Row>,
+{
+    let rows = query.fetch_all(executor).await?;
+
+    let mut decoded = Vec::with_capacity(rows.len());
+    let mut errors = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        match T::from_row(row) {
+            Ok(value) => decoded.push(value),
+            Err(error) => errors.push(RowError { index, error }),
+        }
+    }
+    Ok((decoded, errors))
+}
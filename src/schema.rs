@@ -0,0 +1,251 @@
+//! Checks that existing tables use the column types the codecs expect.
+//!
+//! Run [`verify_schema`] at startup to catch misconfigured tables before the
+//! first decode fails at runtime. Table and column names are bound as
+//! parameters to the catalog query.
+
+use std::fmt;
+
+use sqlx::{ColumnIndex, Database, Decode, Encode, Executor, IntoArguments, Pool, Type};
+
+use crate::dialect::{Backend, Dialect};
+
+/// The primitive a column is expected to hold, and so the codec used for it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EthType {
+    /// `Address` in its 20-byte binary form.
+    Address,
+    /// `B256` in its 32-byte binary form.
+    B256,
+    /// `U256` in its 32-byte big-endian binary form.
+    U256,
+    /// [`U256Numeric`](crate::U256Numeric): `NUMERIC` with at least 78 digits
+    /// and scale 0. Postgres only.
+    U256Numeric,
+    /// [`U256Decimal`](crate::U256Decimal): decimal text.
+    U256Decimal,
+    /// [`AddressText`](crate::AddressText): text of at least 42 characters.
+    AddressText,
+}
+
+impl fmt::Display for EthType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Address => "20-byte binary address",
+            Self::B256 => "32-byte binary hash",
+            Self::U256 => "32-byte binary U256",
+            Self::U256Numeric => "NUMERIC(78, 0) U256",
+            Self::U256Decimal => "decimal text U256",
+            Self::AddressText => "42-character address text",
+        })
+    }
+}
+
+/// Returns the column definition to use for an address `column` on `dialect`.
+///
+/// Every backend stores [`EthType::Address`], the 20-byte binary form:
+/// byte-wise comparison makes `ORDER BY`, `<` and `BETWEEN` agree with
+/// `Address`'s `Ord` without any collation setup. Text columns only compare
+/// correctly when every value is lowercase ([`AddressText`](crate::AddressText)
+/// always writes it) and, on Postgres and MySQL, under a byte-wise collation
+/// (`COLLATE "C"`, or a `_bin` collation); checksummed text never does.
+///
+/// What it takes to keep every value 20 bytes differs per backend:
+/// - MySQL's `BINARY(20)` fixes the width itself.
+/// - Postgres' `BYTEA` has no width, so a `CHECK` pins it.
+/// - SQLite ignores declared types, and compares any `TEXT` value as smaller
+///   than every `BLOB`, so a hex string written by mistake would sort before
+///   all binary addresses. The `CHECK` rejects anything but a 20-byte blob.
+pub fn recommended_address_column(dialect: Dialect, column: &str) -> String {
+    match dialect {
+        Dialect::Postgres => {
+            format!("{column} BYTEA NOT NULL CHECK (octet_length({column}) = 20)")
+        }
+        Dialect::MySql => format!("{column} BINARY(20) NOT NULL"),
+        Dialect::Sqlite => {
+            format!("{column} BLOB NOT NULL CHECK (typeof({column}) = 'blob' AND length({column}) = 20)")
+        }
+    }
+}
+
+/// A column whose database type doesn't fit the expected [`EthType`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaMismatch {
+    pub column: String,
+    pub expected: EthType,
+    /// The column's type as reported by the database, or `None` if the
+    /// column doesn't exist.
+    pub found: Option<String>,
+}
+
+impl fmt::Display for SchemaMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.found {
+            Some(found) => write!(f, "column `{}`: expected {}, found {found}", self.column, self.expected),
+            None => write!(f, "column `{}`: expected {}, column is missing", self.column, self.expected),
+        }
+    }
+}
+
+/// A column as described by the catalog.
+struct ColumnInfo {
+    name: String,
+    data_type: String,
+    char_len: Option<i64>,
+    precision: Option<i64>,
+    scale: Option<i64>,
+}
+
+impl ColumnInfo {
+    fn describe(&self) -> String {
+        match (self.char_len, self.precision, self.scale) {
+            (Some(len), _, _) => format!("{}({len})", self.data_type),
+            (None, Some(p), Some(s)) => format!("{}({p}, {s})", self.data_type),
+            _ => self.data_type.clone(),
+        }
+    }
+}
+
+/// Compares the columns of `table` against `expected` and returns every
+/// mismatch; an empty report means the table fits.
+///
+/// Reads `information_schema.columns` on Postgres (current schema) and MySQL
+/// (current database), and `pragma_table_info` on SQLite. SQLite only has
+/// type affinities, so declared lengths are not checked there.
+pub async fn verify_schema<DB>(
+    pool: &Pool<DB>,
+    table: &str,
+    expected: &[(&str, EthType)],
+) -> Result<Vec<SchemaMismatch>, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> String: Encode<'q, DB>,
+    String: for<'r> Decode<'r, DB> + Type<DB>,
+    i64: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let dialect = DB::DIALECT;
+    let sql = match dialect {
+        Dialect::Postgres => {
+            "SELECT column_name::text, data_type::text, character_maximum_length::int8, \
+             numeric_precision::int8, numeric_scale::int8 \
+             FROM information_schema.columns \
+             WHERE table_schema = current_schema() AND table_name = $1"
+        }
+        Dialect::MySql => {
+            "SELECT CAST(COLUMN_NAME AS CHAR), CAST(DATA_TYPE AS CHAR), \
+             CAST(CHARACTER_MAXIMUM_LENGTH AS SIGNED), CAST(NUMERIC_PRECISION AS SIGNED), \
+             CAST(NUMERIC_SCALE AS SIGNED) \
+             FROM information_schema.COLUMNS \
+             WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?"
+        }
+        Dialect::Sqlite => "SELECT name, type, NULL, NULL, NULL FROM pragma_table_info(?)",
+    };
+    let rows: Vec<(String, String, Option<i64>, Option<i64>, Option<i64>)> = sqlx::query_as(sql)
+        .bind(table.to_string())
+        .fetch_all(pool)
+        .await?;
+    let columns: Vec<ColumnInfo> = rows
+        .into_iter()
+        .map(|(name, data_type, char_len, precision, scale)| ColumnInfo {
+            name,
+            data_type: data_type.to_ascii_lowercase(),
+            char_len,
+            precision,
+            scale,
+        })
+        .collect();
+
+    Ok(expected
+        .iter()
+        .filter_map(|&(column, expected)| {
+            let found = columns.iter().find(|c| c.name.eq_ignore_ascii_case(column));
+            match found {
+                Some(info) if fits(dialect, expected, info) => None,
+                _ => Some(SchemaMismatch {
+                    column: column.to_string(),
+                    expected,
+                    found: found.map(ColumnInfo::describe),
+                }),
+            }
+        })
+        .collect())
+}
+
+fn fits(dialect: Dialect, expected: EthType, info: &ColumnInfo) -> bool {
+    let binary_len = match expected {
+        EthType::Address => Some(20),
+        EthType::B256 | EthType::U256 => Some(32),
+        _ => None,
+    };
+    let ty = info.data_type.as_str();
+
+    match dialect {
+        Dialect::Postgres => match expected {
+            EthType::Address | EthType::B256 | EthType::U256 => ty == "bytea",
+            EthType::U256Numeric => {
+                ty == "numeric"
+                    && info.precision.is_none_or(|p| p >= 78)
+                    && info.scale.is_none_or(|s| s == 0)
+            }
+            EthType::U256Decimal => matches!(ty, "text" | "character varying"),
+            EthType::AddressText => {
+                matches!(ty, "text" | "character varying" | "character")
+                    && info.char_len.is_none_or(|len| len >= 42)
+            }
+        },
+        Dialect::MySql => match (expected, binary_len) {
+            (_, Some(n)) => match ty {
+                "binary" => info.char_len == Some(n),
+                "varbinary" => info.char_len.is_some_and(|len| len >= n),
+                _ => matches!(ty, "tinyblob" | "blob" | "mediumblob" | "longblob"),
+            },
+            // DECIMAL tops out at 65 digits
+            (EthType::U256Numeric, _) => false,
+            (EthType::U256Decimal, _) => {
+                matches!(ty, "text" | "mediumtext" | "longtext")
+                    || (ty == "varchar" && info.char_len.is_some_and(|len| len >= 78))
+            }
+            (EthType::AddressText, _) | (_, None) => {
+                matches!(ty, "text" | "mediumtext" | "longtext")
+                    || (matches!(ty, "char" | "varchar") && info.char_len.is_some_and(|len| len >= 42))
+            }
+        },
+        Dialect::Sqlite => match expected {
+            EthType::Address | EthType::B256 | EthType::U256 => {
+                matches!(sqlite_affinity(ty), Affinity::Blob | Affinity::Numeric)
+            }
+            EthType::U256Numeric => false,
+            EthType::U256Decimal | EthType::AddressText => sqlite_affinity(ty) == Affinity::Text,
+        },
+    }
+}
+
+#[derive(PartialEq)]
+enum Affinity {
+    Integer,
+    Text,
+    Blob,
+    Real,
+    Numeric,
+}
+
+/// Column affinity per <https://www.sqlite.org/datatype3.html#determination_of_column_affinity>.
+///
+/// `BINARY(20)` gets `NUMERIC` affinity, which stores blobs unchanged.
+fn sqlite_affinity(declared: &str) -> Affinity {
+    let declared = declared.to_ascii_uppercase();
+    if declared.contains("INT") {
+        Affinity::Integer
+    } else if ["CHAR", "CLOB", "TEXT"].iter().any(|t| declared.contains(t)) {
+        Affinity::Text
+    } else if declared.is_empty() || declared.contains("BLOB") {
+        Affinity::Blob
+    } else if ["REAL", "FLOA", "DOUB"].iter().any(|t| declared.contains(t)) {
+        Affinity::Real
+    } else {
+        Affinity::Numeric
+    }
+}
@@ -0,0 +1,49 @@
+//! `#[serde(with = ...)]` modules choosing how an [`Address`] is written in
+//! JSON, independently of the column codec.
+//!
+//! alloy-primitives serializes addresses in EIP-55 checksummed form. Use
+//! [`lower`] for payloads that are compared or indexed as text, and
+//! [`checksum`] to make the checksummed form explicit and validated:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Transfer {
+//!     #[serde(with = "test_alloy_sqlx::serde_address_lower")]
+//!     from: Address,
+//! }
+//! ```
+
+use alloy_primitives::{Address, hex};
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+use crate::address::{parse_address_str, parse_address_str_strict};
+
+/// Writes EIP-55 checksummed hex. Reads any case, rejecting mixed-case text
+/// whose checksum doesn't match.
+pub mod checksum {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(address: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&address.to_checksum(None))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        parse_address_str_strict(&text).map_err(D::Error::custom)
+    }
+}
+
+/// Writes lowercase `0x`-prefixed hex. Reads any case without checksum
+/// validation.
+pub mod lower {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(address: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode_prefixed(address))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        parse_address_str(&text).map_err(D::Error::custom)
+    }
+}
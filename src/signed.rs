@@ -0,0 +1,161 @@
+//! `I256` stored as a fixed-width sign+magnitude blob.
+
+use std::cmp::Ordering;
+
+use alloy_primitives::{I256, Sign, U256};
+use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+use crate::dialect::Dialect;
+
+/// Length of the stored blob: 1 sign byte followed by a 32-byte magnitude.
+pub const I256_BLOB_LEN: usize = 1 + 32;
+
+const SIGN_NON_NEGATIVE: u8 = 0x00;
+const SIGN_NEGATIVE: u8 = 0x01;
+
+/// An [`I256`] stored as `[1 sign][32 magnitude]` in a binary column.
+///
+/// The sign byte is `0x00` for zero and positive values and `0x01` for
+/// negative ones; the magnitude is big-endian. Every value has exactly one
+/// encoding, but byte order does not match numeric order for negative
+/// values: sort raw blobs with [`i256_blob_cmp`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I256Blob(pub I256);
+
+impl From<I256> for I256Blob {
+    fn from(value: I256) -> Self {
+        Self(value)
+    }
+}
+
+impl From<I256Blob> for I256 {
+    fn from(value: I256Blob) -> Self {
+        value.0
+    }
+}
+
+impl I256Blob {
+    /// Returns the stored blob.
+    pub fn to_bytes(&self) -> [u8; I256_BLOB_LEN] {
+        let mut bytes = [0u8; I256_BLOB_LEN];
+        bytes[0] = if self.0.is_negative() { SIGN_NEGATIVE } else { SIGN_NON_NEGATIVE };
+        bytes[1..].copy_from_slice(&self.0.unsigned_abs().to_be_bytes::<32>());
+        bytes
+    }
+
+    /// Parses a stored blob, rejecting bad lengths, unknown sign bytes,
+    /// negative zero and magnitudes outside the `I256` range.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BoxDynError> {
+        if bytes.len() != I256_BLOB_LEN {
+            return Err(format!(
+                "invalid I256 blob length: expected {I256_BLOB_LEN} bytes, got {}",
+                bytes.len()
+            )
+            .into());
+        }
+        let magnitude = U256::from_be_slice(&bytes[1..]);
+        let sign = match bytes[0] {
+            SIGN_NON_NEGATIVE => Sign::Positive,
+            SIGN_NEGATIVE if magnitude.is_zero() => {
+                return Err("invalid I256 blob: negative zero".into());
+            }
+            SIGN_NEGATIVE => Sign::Negative,
+            other => return Err(format!("invalid I256 blob sign byte {other:#04x}").into()),
+        };
+        I256::checked_from_sign_and_abs(sign, magnitude)
+            .map(Self)
+            .ok_or_else(|| "invalid I256 blob: magnitude out of range".into())
+    }
+}
+
+/// Compares two stored blobs in numeric order.
+///
+/// Negative values sort before non-negative ones, and larger magnitudes sort
+/// first among negatives. Blobs are expected to come from [`I256Blob`];
+/// malformed input is compared without panicking but in no useful order.
+pub fn i256_blob_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let negative = |blob: &[u8]| blob.first() == Some(&SIGN_NEGATIVE);
+    match (negative(a), negative(b)) {
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (true, true) => b.get(1..).cmp(&a.get(1..)),
+        (false, false) => a.get(1..).cmp(&b.get(1..)),
+    }
+}
+
+/// Builds a predicate selecting the [`I256Blob`] values in `column` that lie
+/// in the numeric range `lo..=hi`.
+///
+/// Negative blobs sort above non-negative ones and in reverse magnitude
+/// order, so a plain `BETWEEN` on the blobs is wrong as soon as the range
+/// reaches below zero. The range is split by sign instead: negatives become
+/// `BETWEEN blob(hi) AND blob(lo)`, non-negatives keep their natural order,
+/// and a range spanning zero is the `OR` of both. Placeholders are numbered
+/// from `first_param`; returns the predicate and the blobs to bind, in
+/// placeholder order. An empty range (`lo > hi`) yields `1 = 0`.
+pub fn i256_blob_range(
+    dialect: Dialect,
+    column: &str,
+    first_param: usize,
+    lo: I256,
+    hi: I256,
+) -> (String, Vec<I256Blob>) {
+    if lo > hi {
+        return ("1 = 0".to_string(), Vec::new());
+    }
+    let between = |n: usize| {
+        let (start, end) = (dialect.placeholder(n), dialect.placeholder(n + 1));
+        format!("{column} BETWEEN {start} AND {end}")
+    };
+    match (lo.is_negative(), hi.is_negative()) {
+        (false, false) => (between(first_param), vec![I256Blob(lo), I256Blob(hi)]),
+        (true, true) => (between(first_param), vec![I256Blob(hi), I256Blob(lo)]),
+        (true, false) => (
+            format!("({} OR {})", between(first_param), between(first_param + 2)),
+            vec![I256Blob(I256::MINUS_ONE), I256Blob(lo), I256Blob(I256::ZERO), I256Blob(hi)],
+        ),
+        (false, true) => unreachable!("lo <= hi"),
+    }
+}
+
+impl<DB: Database> Type<DB> for I256Blob
+where
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for I256Blob
+where
+    Vec<u8>: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let bytes = self.to_bytes().to_vec();
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", bytes.len());
+        <Vec<u8> as Encode<'q, DB>>::encode(bytes, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for I256Blob
+where
+    Vec<u8>: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let bytes = <Vec<u8> as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, bytes.len());
+        Self::from_bytes(&bytes)
+    }
+}
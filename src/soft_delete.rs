@@ -0,0 +1,35 @@
+//! Addresses carrying an in-row soft-delete tombstone.
+//!
+//! Tables are expected to have an `address` column and a boolean `deleted`
+//! column.
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use sqlx::{ColumnIndex, Database, Decode, Executor, FromRow, IntoArguments, Type};
+
+/// An address row together with its tombstone flag.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, FromRow)]
+pub struct MaybeDeletedAddress {
+    pub address: Address,
+    pub deleted: bool,
+}
+
+impl MaybeDeletedAddress {
+    /// Returns the address unless the row is tombstoned.
+    pub fn active(&self) -> Option<Address> {
+        (!self.deleted).then_some(self.address)
+    }
+}
+
+/// Returns every non-tombstoned address in `table`, in byte order.
+pub async fn active_addresses<'c, E, DB>(executor: E, table: &str) -> Result<Vec<Address>, sqlx::Error>
+where
+    DB: Database,
+    E: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let sql = format!("SELECT address FROM {table} WHERE NOT deleted ORDER BY address");
+    sqlx::query_scalar(&sql).fetch_all(executor).await
+}
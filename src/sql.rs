@@ -0,0 +1,131 @@
+//! Parameterized SQL fragment builders.
+//!
+//! Values always travel as bind parameters; only column names, which must
+//! come from trusted code, are interpolated.
+
+use std::{fmt, str::FromStr};
+
+use alloy_primitives::Address;
+use sqlx::{Encode, QueryBuilder, Type};
+
+use crate::dialect::{Backend, Dialect};
+
+/// Builds a `column IN (...)` predicate with one placeholder per address.
+///
+/// Returns the fragment and the addresses to bind, in placeholder order.
+/// Postgres placeholders are numbered from `$1`. An empty slice yields the
+/// always-false `1 = 0` so the query stays valid.
+pub fn in_clause_addresses(
+    dialect: Dialect,
+    column: &str,
+    addresses: &[Address],
+) -> (String, Vec<Address>) {
+    if addresses.is_empty() {
+        return ("1 = 0".to_string(), Vec::new());
+    }
+    let placeholders = (1..=addresses.len())
+        .map(|n| dialect.placeholder(n))
+        .collect::<Vec<_>>()
+        .join(", ");
+    (format!("{column} IN ({placeholders})"), addresses.to_vec())
+}
+
+/// Pushes `addr` as a bind parameter, followed by an explicit `::bytea` cast
+/// on Postgres.
+///
+/// The cast spells the parameter type out in the SQL text, so expressions
+/// whose type Postgres derives from the parameter (a CTE column, a `UNION`
+/// arm, a `CASE` branch) don't depend on inference. Other backends get the
+/// plain placeholder.
+pub fn bind_address_cast<'a, 'args, DB>(
+    builder: &'a mut QueryBuilder<'args, DB>,
+    addr: Address,
+) -> &'a mut QueryBuilder<'args, DB>
+where
+    DB: Backend,
+    Address: Encode<'args, DB> + Type<DB>,
+{
+    builder.push_bind(addr);
+    if DB::DIALECT == Dialect::Postgres {
+        builder.push("::bytea");
+    }
+    builder
+}
+
+/// Builds a Postgres predicate comparing a text address column to `address`
+/// regardless of case, matching the index from
+/// [`lower_address_index`](crate::ddl::lower_address_index).
+///
+/// `param` is the 1-based placeholder number; both sides go through
+/// `lower()`, so checksummed input matches lowercase storage and vice versa.
+/// Returns the predicate and the text to bind.
+pub fn lower_address_eq(column: &str, param: usize, address: &str) -> (String, String) {
+    (format!("lower({column}) = lower(${param})"), address.to_string())
+}
+
+/// Columns an admin UI may sort by.
+///
+/// User input is parsed into this enum with [`FromStr`], so only known
+/// column names can ever reach the SQL string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrimitiveColumn {
+    Address,
+    Balance,
+}
+
+/// Sort direction for [`order_by`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SortDir {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Rejected sort column or direction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownSortKey(pub String);
+
+impl fmt::Display for UnknownSortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown sort key {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownSortKey {}
+
+impl FromStr for PrimitiveColumn {
+    type Err = UnknownSortKey;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "address" => Ok(Self::Address),
+            "balance" => Ok(Self::Balance),
+            _ => Err(UnknownSortKey(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for SortDir {
+    type Err = UnknownSortKey;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            _ => Err(UnknownSortKey(s.to_string())),
+        }
+    }
+}
+
+/// Returns an `ORDER BY` clause for a whitelisted column.
+///
+/// Both columns are expected to use sortable encodings (binary `Address`,
+/// big-endian `U256`), so the database order matches Rust's.
+pub fn order_by(column: PrimitiveColumn, dir: SortDir) -> &'static str {
+    match (column, dir) {
+        (PrimitiveColumn::Address, SortDir::Asc) => "ORDER BY address ASC",
+        (PrimitiveColumn::Address, SortDir::Desc) => "ORDER BY address DESC",
+        (PrimitiveColumn::Balance, SortDir::Asc) => "ORDER BY balance ASC",
+        (PrimitiveColumn::Balance, SortDir::Desc) => "ORDER BY balance DESC",
+    }
+}
@@ -0,0 +1,482 @@
+//! Query helpers for address-keyed tables.
+//!
+//! Tables are expected to have a unique binary `address` column. Table names
+//! are interpolated into the SQL and must come from trusted code.
+
+use std::{collections::HashMap, fmt};
+
+use alloy_primitives::{Address, I256, Sign, U256};
+use futures_util::TryStreamExt;
+use sqlx::{ColumnIndex, Database, Decode, Encode, Executor, IntoArguments, Pool, Transaction, Type};
+
+use crate::{
+    dialect::{Backend, Dialect},
+    numeric::parse_decimal_u256,
+    sql::in_clause_addresses,
+};
+
+/// Whether an upsert created a new row or updated an existing one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Inserted,
+    Updated,
+}
+
+/// Inserts `addr` with `label`, or updates the label if the address exists.
+///
+/// The outcome comes from the write itself, so concurrent callers can't both
+/// see `Inserted`: Postgres reports it through `RETURNING (xmax = 0)` on an
+/// `ON CONFLICT DO UPDATE`. On MySQL the `ON DUPLICATE KEY UPDATE` branch
+/// calls `LAST_INSERT_ID(0)`, so the statement reports a last insert id of 0
+/// for an update and the freshly generated id for an insert; the table needs
+/// an `AUTO_INCREMENT` `id` column. Affected-row counts can't be used there,
+/// since sqlx connects with `CLIENT_FOUND_ROWS` and an update that keeps the
+/// same label counts 1, like an insert. SQLite tries
+/// `ON CONFLICT DO NOTHING` and falls back to an `UPDATE` in the same
+/// transaction, which holds the database write lock throughout.
+pub async fn upsert_address<DB>(
+    pool: &Pool<DB>,
+    table: &str,
+    addr: Address,
+    label: &str,
+) -> Result<UpsertOutcome, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'c> &'c mut <DB as Database>::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    Address: Type<DB>,
+    for<'q> String: Encode<'q, DB>,
+    String: Type<DB>,
+    bool: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let dialect = DB::DIALECT;
+    let (p1, p2) = (dialect.placeholder(1), dialect.placeholder(2));
+    let inserted = match dialect {
+        Dialect::Postgres => {
+            // `xmax` is 0 on a freshly inserted row version
+            sqlx::query_scalar(&format!(
+                "INSERT INTO {table} (address, label) VALUES ({p1}, {p2}) \
+                 ON CONFLICT (address) DO UPDATE SET label = excluded.label \
+                 RETURNING (xmax = 0)"
+            ))
+            .bind(addr)
+            .bind(label.to_string())
+            .fetch_one(pool)
+            .await?
+        }
+        Dialect::MySql => {
+            let result = sqlx::query(&format!(
+                "INSERT INTO {table} (address, label) VALUES ({p1}, {p2}) \
+                 ON DUPLICATE KEY UPDATE label = VALUES(label), id = id + LAST_INSERT_ID(0)"
+            ))
+            .bind(addr)
+            .bind(label.to_string())
+            .execute(pool)
+            .await?;
+            // Only an insert generates a non-zero id
+            DB::last_insert_id(&result).is_some_and(|id| id != 0)
+        }
+        Dialect::Sqlite => {
+            let mut tx = pool.begin().await?;
+            let result = sqlx::query(&format!(
+                "INSERT INTO {table} (address, label) VALUES ({p1}, {p2}) \
+                 ON CONFLICT (address) DO NOTHING"
+            ))
+            .bind(addr)
+            .bind(label.to_string())
+            .execute(&mut *tx)
+            .await?;
+            let inserted = DB::rows_affected(&result) == 1;
+            if !inserted {
+                sqlx::query(&format!("UPDATE {table} SET label = {p1} WHERE address = {p2}"))
+                    .bind(label.to_string())
+                    .bind(addr)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+            tx.commit().await?;
+            inserted
+        }
+    };
+
+    Ok(if inserted { UpsertOutcome::Inserted } else { UpsertOutcome::Updated })
+}
+
+/// A balance helper failed.
+///
+/// Problems with the stored balances themselves get their own variants, so
+/// they can't be mistaken for a database or codec failure in [`Self::Db`].
+#[derive(Debug)]
+pub enum BalanceError {
+    /// The address appears in more than one row.
+    Duplicate { address: Address },
+    /// No row holds the address.
+    NotFound { address: Address },
+    /// Applying `delta` would take the balance below zero.
+    Underflow { address: Address, balance: U256, delta: I256 },
+    /// Applying `delta` would take the balance above `U256::MAX`.
+    Overflow { address: Address, balance: U256, delta: I256 },
+    /// The query or the decoding of a row failed.
+    Db(sqlx::Error),
+}
+
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate { address } => write!(f, "duplicate address {address}"),
+            Self::NotFound { address } => write!(f, "no balance for address {address}"),
+            Self::Underflow { address, balance, delta } => {
+                write!(f, "balance {balance} of {address} underflows by {delta}")
+            }
+            Self::Overflow { address, balance, delta } => {
+                write!(f, "balance {balance} of {address} overflows by {delta}")
+            }
+            Self::Db(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for BalanceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Db(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<sqlx::Error> for BalanceError {
+    fn from(err: sqlx::Error) -> Self {
+        Self::Db(err)
+    }
+}
+
+/// Reads every `(address, balance)` row of `table` into a map.
+///
+/// Fails with [`BalanceError::Duplicate`] if an address appears more than
+/// once, since silently keeping one of the balances would hide a data problem.
+pub async fn fetch_balances<'c, E, DB>(
+    executor: E,
+    table: &str,
+) -> Result<HashMap<Address, U256>, BalanceError>
+where
+    DB: Database,
+    E: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    Address: for<'r> Decode<'r, DB> + Type<DB>,
+    U256: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let rows: Vec<(Address, U256)> = sqlx::query_as(&format!("SELECT address, balance FROM {table}"))
+        .fetch_all(executor)
+        .await?;
+
+    let mut balances = HashMap::with_capacity(rows.len());
+    for (address, balance) in rows {
+        if balances.insert(address, balance).is_some() {
+            return Err(BalanceError::Duplicate { address });
+        }
+    }
+    Ok(balances)
+}
+
+/// Counts the addresses in `table` by their leading byte.
+///
+/// The grouping runs in the database on the binary `address` column; index
+/// `b` of the result holds the number of addresses starting with byte `b`.
+pub async fn count_by_first_byte<DB>(pool: &Pool<DB>, table: &str) -> Result<[u64; 256], sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    String: for<'r> Decode<'r, DB> + Type<DB>,
+    i64: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    // Hex keeps the bucket column a string on every backend; the byte
+    // extraction functions disagree on their integer types.
+    let first_byte = match DB::DIALECT {
+        Dialect::Postgres => "encode(substring(address from 1 for 1), 'hex')",
+        Dialect::MySql => "HEX(SUBSTRING(address, 1, 1))",
+        Dialect::Sqlite => "hex(substr(address, 1, 1))",
+    };
+    let rows: Vec<(String, i64)> = sqlx::query_as(&format!(
+        "SELECT {first_byte} AS first_byte, COUNT(*) FROM {table} GROUP BY first_byte"
+    ))
+    .fetch_all(pool)
+    .await?;
+
+    let mut counts = [0u64; 256];
+    for (bucket, count) in rows {
+        let byte = u8::from_str_radix(&bucket, 16).map_err(|e| {
+            sqlx::Error::Decode(format!("invalid leading byte {bucket:?} in {table}: {e}").into())
+        })?;
+        counts[byte as usize] = count as u64;
+    }
+    Ok(counts)
+}
+
+/// Returns whether `table` has a row whose `column` equals `addr`.
+///
+/// Runs `SELECT EXISTS(SELECT 1 ...)`, so the database can stop at the first
+/// match. The column name is interpolated and must come from trusted code.
+pub async fn address_exists<DB>(
+    pool: &Pool<DB>,
+    table: &str,
+    column: &str,
+    addr: Address,
+) -> Result<bool, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    Address: Type<DB>,
+    bool: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let p1 = DB::DIALECT.placeholder(1);
+    sqlx::query_scalar(&format!("SELECT EXISTS(SELECT 1 FROM {table} WHERE {column} = {p1})"))
+        .bind(addr)
+        .fetch_one(pool)
+        .await
+}
+
+/// Counts the distinct addresses in `column` of `table`.
+///
+/// Runs `SELECT COUNT(DISTINCT column)` in the database. On the binary
+/// column each address has exactly one encoding, so the count is exact. Text
+/// columns are compared as strings: a checksummed and a lowercase spelling of
+/// the same address count twice unless the column is normalized (e.g. always
+/// written through [`AddressText`](crate::AddressText)).
+pub async fn count_distinct_addresses<DB>(pool: &Pool<DB>, table: &str, column: &str) -> Result<u64, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    i64: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(DISTINCT {column}) FROM {table}"))
+        .fetch_one(pool)
+        .await?;
+    Ok(count as u64)
+}
+
+/// Returns the `id` of the row of `table` holding `addr`, inserting one first
+/// if there is none.
+///
+/// On Postgres and SQLite the insert skips conflicting rows (`ON CONFLICT DO
+/// NOTHING ... RETURNING`), so a concurrent insert of the same address falls
+/// back to reading its id. On MySQL a conflicting insert runs
+/// `ON DUPLICATE KEY UPDATE id = LAST_INSERT_ID(id)`, which sets
+/// `last_insert_id` to the existing row's id, so both cases are answered by
+/// the insert itself. The table needs an integer `id` primary key and a unique
+/// `address` column.
+pub async fn get_or_insert_address<DB>(pool: &Pool<DB>, table: &str, addr: Address) -> Result<i64, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    Address: Type<DB>,
+    i64: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let dialect = DB::DIALECT;
+    let p1 = dialect.placeholder(1);
+    let id = dialect.bigint("id");
+    let select = format!("SELECT {id} FROM {table} WHERE address = {p1}");
+
+    let existing: Option<i64> = sqlx::query_scalar(&select).bind(addr).fetch_optional(pool).await?;
+    if let Some(existing) = existing {
+        return Ok(existing);
+    }
+
+    let inserted = match dialect {
+        Dialect::Postgres | Dialect::Sqlite => {
+            sqlx::query_scalar(&format!(
+                "INSERT INTO {table} (address) VALUES ({p1}) \
+                 ON CONFLICT (address) DO NOTHING RETURNING {id}"
+            ))
+            .bind(addr)
+            .fetch_optional(pool)
+            .await?
+        }
+        Dialect::MySql => {
+            let result = sqlx::query(&format!(
+                "INSERT INTO {table} (address) VALUES ({p1}) \
+                 ON DUPLICATE KEY UPDATE id = LAST_INSERT_ID(id)"
+            ))
+            .bind(addr)
+            .execute(pool)
+            .await?;
+            DB::last_insert_id(&result)
+        }
+    };
+    match inserted {
+        Some(id) => Ok(id),
+        None => sqlx::query_scalar(&select).bind(addr).fetch_one(pool).await,
+    }
+}
+
+/// Deletes the rows of `table` whose `address` is in `addrs`, returning the
+/// number of rows removed.
+///
+/// Runs a single `DELETE ... WHERE address IN (...)` with one placeholder per
+/// address; an empty slice is a no-op that doesn't touch the database. Very
+/// large sets can exceed the backend's bind parameter limit (65535 on
+/// Postgres and MySQL, 32766 on recent SQLite), so chunk them.
+pub async fn delete_addresses<DB>(pool: &Pool<DB>, table: &str, addrs: &[Address]) -> Result<u64, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    Address: Type<DB>,
+{
+    if addrs.is_empty() {
+        return Ok(0);
+    }
+    let (predicate, binds) = in_clause_addresses(DB::DIALECT, "address", addrs);
+    let sql = format!("DELETE FROM {table} WHERE {predicate}");
+    let mut query = sqlx::query(&sql);
+    for addr in binds {
+        query = query.bind(addr);
+    }
+    let result = query.execute(pool).await?;
+    Ok(DB::rows_affected(&result))
+}
+
+/// Runs `sql`, a Postgres query returning one `NUMERIC` aggregate cast to
+/// `::text`, and parses the result. `NULL` (an empty table) is `None`.
+///
+/// Text sidesteps NUMERIC decoding in this generic context; a value outside
+/// the `U256` range fails with [`sqlx::Error::Decode`].
+async fn fetch_u256_text<DB>(pool: &Pool<DB>, sql: &str) -> Result<Option<U256>, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    String: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let text: Option<String> = sqlx::query_scalar(sql).fetch_one(pool).await?;
+    text.map(|text| parse_decimal_u256(&text).map_err(sqlx::Error::Decode)).transpose()
+}
+
+/// Sums the `U256` amounts in `column` of `table`; an empty table sums to zero.
+///
+/// On Postgres the column must be `NUMERIC` and the sum runs in the database;
+/// a total beyond `U256::MAX` fails with [`sqlx::Error::Decode`]. MySQL and
+/// SQLite have no numeric type wide enough, so the binary column is streamed
+/// and summed with checked addition, failing the same way on overflow.
+pub async fn sum_u256<DB>(pool: &Pool<DB>, table: &str, column: &str) -> Result<U256, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    String: for<'r> Decode<'r, DB> + Type<DB>,
+    U256: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let overflow = || sqlx::Error::Decode(format!("sum of {table}.{column} overflows U256").into());
+
+    if DB::DIALECT == Dialect::Postgres {
+        let total = fetch_u256_text(pool, &format!("SELECT SUM({column})::text FROM {table}")).await?;
+        return Ok(total.unwrap_or_default());
+    }
+
+    let sql = format!("SELECT {column} FROM {table}");
+    let mut values = sqlx::query_scalar::<DB, U256>(&sql).fetch(pool);
+    let mut total = U256::ZERO;
+    while let Some(value) = values.try_next().await? {
+        total = total.checked_add(value).ok_or_else(overflow)?;
+    }
+    Ok(total)
+}
+
+/// Returns the largest `U256` in `column` of `table`, or `None` if the table
+/// is empty.
+///
+/// On Postgres the column must be `NUMERIC` and `MAX` runs in the database.
+/// Elsewhere the column holds the 32-byte big-endian blob, whose byte order
+/// matches numeric order, so the maximum is the first row of a descending
+/// scan (which an index on the column serves directly).
+pub async fn max_u256<DB>(pool: &Pool<DB>, table: &str, column: &str) -> Result<Option<U256>, sqlx::Error>
+where
+    DB: Backend,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    String: for<'r> Decode<'r, DB> + Type<DB>,
+    U256: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    if DB::DIALECT == Dialect::Postgres {
+        return fetch_u256_text(pool, &format!("SELECT MAX({column})::text FROM {table}")).await;
+    }
+
+    sqlx::query_scalar(&format!("SELECT {column} FROM {table} ORDER BY {column} DESC LIMIT 1"))
+        .fetch_optional(pool)
+        .await
+}
+
+/// Applies a signed `delta` to the `balance` of `addr` in `table` within the
+/// caller's transaction, returning the new balance.
+///
+/// The row is read (`FOR UPDATE` on Postgres and MySQL, so concurrent
+/// adjustments serialize), updated with checked arithmetic and written back.
+/// A result below zero or above `U256::MAX` fails with
+/// [`BalanceError::Underflow`] or [`BalanceError::Overflow`] and writes
+/// nothing; a missing row is [`BalanceError::NotFound`]. Committing is left
+/// to the caller.
+pub async fn adjust_balance<DB>(
+    tx: &mut Transaction<'_, DB>,
+    table: &str,
+    addr: Address,
+    delta: I256,
+) -> Result<U256, BalanceError>
+where
+    DB: Backend,
+    for<'c> &'c mut <DB as Database>::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+    for<'q> Address: Encode<'q, DB>,
+    for<'q> U256: Encode<'q, DB>,
+    Address: Type<DB>,
+    U256: for<'r> Decode<'r, DB> + Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    let dialect = DB::DIALECT;
+    let (p1, p2) = (dialect.placeholder(1), dialect.placeholder(2));
+    // SQLite takes a database-wide write lock instead of row locks
+    let lock = match dialect {
+        Dialect::Postgres | Dialect::MySql => " FOR UPDATE",
+        Dialect::Sqlite => "",
+    };
+
+    let current: U256 = sqlx::query_scalar(&format!("SELECT balance FROM {table} WHERE address = {p1}{lock}"))
+        .bind(addr)
+        .fetch_optional(&mut **tx)
+        .await?
+        .ok_or(BalanceError::NotFound { address: addr })?;
+
+    let (sign, magnitude) = delta.into_sign_and_abs();
+    let updated = match sign {
+        Sign::Positive => current
+            .checked_add(magnitude)
+            .ok_or(BalanceError::Overflow { address: addr, balance: current, delta })?,
+        Sign::Negative => current
+            .checked_sub(magnitude)
+            .ok_or(BalanceError::Underflow { address: addr, balance: current, delta })?,
+    };
+
+    sqlx::query(&format!("UPDATE {table} SET balance = {p1} WHERE address = {p2}"))
+        .bind(updated)
+        .bind(addr)
+        .execute(&mut **tx)
+        .await?;
+    Ok(updated)
+}
@@ -0,0 +1,28 @@
+//! Debug-level codec events, enabled by the `trace-codec` feature.
+//!
+//! Every `Encode` and `Decode` impl defined in this crate emits one event per
+//! value, including wrappers that delegate to another codec (those report
+//! the delegated width, e.g. 8 bytes for an integer column). The binary
+//! `Address`, `FixedBytes` and `U256` impls live in alloy-primitives and ruint
+//! and are not traced when used directly.
+
+use std::any::type_name;
+
+use sqlx::{TypeInfo, ValueRef};
+use tracing::debug;
+
+/// Returns the database type name of a value that is about to be decoded.
+pub(crate) fn column_type<'r, V: ValueRef<'r>>(value: &V) -> String {
+    value.type_info().name().to_string()
+}
+
+/// Records that a `T` was encoded as `len` bytes of `repr`, the stored
+/// representation: `text`, `blob`, `int`, `numeric`, `bit` or `json`.
+pub(crate) fn encoded<T>(repr: &str, len: usize) {
+    debug!(rust_type = type_name::<T>(), repr, len, "encode primitive");
+}
+
+/// Records that a `T` was decoded from `len` bytes of a `column_type` value.
+pub(crate) fn decoded<T>(column_type: &str, len: usize) {
+    debug!(rust_type = type_name::<T>(), column_type, len, "decode primitive");
+}
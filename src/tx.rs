@@ -0,0 +1,73 @@
+//! Codecs for transaction fields.
+
+use alloy_primitives::{Address, TxKind};
+use sqlx::{Database, Decode, Encode, Type, ValueRef, encode::IsNull, error::BoxDynError};
+
+/// A transaction `to` field stored as a nullable address column.
+///
+/// [`TxKind::Create`] is written as SQL `NULL` and [`TxKind::Call`] as the
+/// address, matching how block explorers store contract creations. A `NULL`
+/// column decodes back to [`TxKind::Create`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TxKindAddress(pub TxKind);
+
+impl From<TxKind> for TxKindAddress {
+    fn from(kind: TxKind) -> Self {
+        Self(kind)
+    }
+}
+
+impl From<TxKindAddress> for TxKind {
+    fn from(kind: TxKindAddress) -> Self {
+        kind.0
+    }
+}
+
+impl<DB: Database> Type<DB> for TxKindAddress
+where
+    Address: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Address as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Address as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for TxKindAddress
+where
+    Address: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", self.0.to().map_or(0, |address| address.len()));
+        match &self.0 {
+            TxKind::Create => Ok(IsNull::Yes),
+            TxKind::Call(address) => <Address as Encode<'q, DB>>::encode_by_ref(address, buf),
+        }
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for TxKindAddress
+where
+    Address: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        if value.is_null() {
+            #[cfg(feature = "trace-codec")]
+            crate::trace::decoded::<Self>(&column_type, 0);
+            return Ok(Self(TxKind::Create));
+        }
+        let address = <Address as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, address.len());
+        Ok(Self(TxKind::Call(address)))
+    }
+}
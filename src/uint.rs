@@ -0,0 +1,158 @@
+//! Fixed-width binary storage for `Uint`s of any size.
+//!
+//! ruint's own sqlx impls already store every `Uint<BITS, LIMBS>` as a
+//! big-endian blob, but decode any blob whose value fits, including short
+//! ones. [`UintBlob`] additionally requires the exact width, so a truncated
+//! or misrouted column fails loudly. [`SmallU256`] reads such a column into
+//! an `i64` when the values are known to be small.
+
+use alloy_primitives::{U256, Uint};
+use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+/// A [`Uint`] stored as exactly `Uint::<BITS, LIMBS>::BYTES` big-endian bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UintBlob<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
+
+/// 96-bit packed fields (e.g. timestamp + value) as 12-byte blobs.
+pub type U96Blob = UintBlob<96, 2>;
+
+/// 512-bit values, e.g. KZG commitment scalars, as 64-byte blobs.
+pub type U512Blob = UintBlob<512, 8>;
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for UintBlob<BITS, LIMBS> {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<UintBlob<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    fn from(value: UintBlob<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> UintBlob<BITS, LIMBS> {
+    /// Parses a stored blob of exactly `Uint::<BITS, LIMBS>::BYTES` bytes.
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, BoxDynError> {
+        let expected = Uint::<BITS, LIMBS>::BYTES;
+        if bytes.len() != expected {
+            return Err(format!(
+                "invalid Uint<{BITS}> length: expected {expected} bytes, got {}",
+                bytes.len()
+            )
+            .into());
+        }
+        Uint::try_from_be_slice(bytes)
+            .map(Self)
+            .ok_or_else(|| format!("value does not fit in Uint<{BITS}>").into())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize, DB: Database> Type<DB> for UintBlob<BITS, LIMBS>
+where
+    Vec<u8>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, const BITS: usize, const LIMBS: usize, DB: Database> Encode<'q, DB> for UintBlob<BITS, LIMBS>
+where
+    Vec<u8>: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let bytes = self.0.to_be_bytes_vec();
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", bytes.len());
+        <Vec<u8> as Encode<'q, DB>>::encode(bytes, buf)
+    }
+}
+
+impl<'r, const BITS: usize, const LIMBS: usize, DB: Database> Decode<'r, DB> for UintBlob<BITS, LIMBS>
+where
+    Vec<u8>: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let bytes = <Vec<u8> as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, bytes.len());
+        Self::from_be_slice(&bytes)
+    }
+}
+
+/// A `U256` column read as an `i64`, for counters and ids that are stored
+/// with the 32-byte codec but are small in practice.
+///
+/// Decoding fails instead of wrapping when the stored value exceeds
+/// `i64::MAX`; encoding fails for negative values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SmallU256(pub i64);
+
+impl From<SmallU256> for i64 {
+    fn from(value: SmallU256) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<U256> for SmallU256 {
+    type Error = BoxDynError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        i64::try_from(value)
+            .map(Self)
+            .map_err(|_| format!("U256 value {value} does not fit in i64").into())
+    }
+}
+
+impl<DB: Database> Type<DB> for SmallU256
+where
+    U256: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <U256 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <U256 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for SmallU256
+where
+    U256: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let value = U256::try_from(self.0)
+            .map_err(|_| format!("cannot encode negative value {} as U256", self.0))?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::encoded::<Self>("blob", U256::BYTES);
+        <U256 as Encode<'q, DB>>::encode(value, buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for SmallU256
+where
+    U256: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "trace-codec")]
+        let column_type = crate::trace::column_type(&value);
+        let value = <U256 as Decode<'r, DB>>::decode(value)?;
+        #[cfg(feature = "trace-codec")]
+        crate::trace::decoded::<Self>(&column_type, U256::BYTES);
+        value.try_into()
+    }
+}
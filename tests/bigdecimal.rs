@@ -0,0 +1,42 @@
+//! Tests for converting `U256` to and from `BigDecimal`
+#![cfg(feature = "bigdecimal")]
+
+use std::str::FromStr;
+
+use alloy_primitives::U256;
+use sqlx::types::BigDecimal;
+use test_alloy_sqlx::{bigdecimal_to_u256, u256_to_bigdecimal};
+
+#[test]
+fn test_bigdecimal_round_trip() {
+    for value in [U256::ZERO, U256::from(1), U256::from(10).pow(U256::from(18)), U256::MAX] {
+        let decimal = u256_to_bigdecimal(value);
+        assert_eq!(bigdecimal_to_u256(&decimal).expect("Failed to convert back"), value);
+    }
+}
+
+#[test]
+fn test_bigdecimal_integral_with_scale() {
+    let decimal = BigDecimal::from_str("42.000").expect("Failed to parse BigDecimal");
+    assert_eq!(bigdecimal_to_u256(&decimal).expect("Failed to convert"), U256::from(42));
+
+    // Stored with a negative scale internally
+    let decimal = BigDecimal::from_str("1e30").expect("Failed to parse BigDecimal");
+    assert_eq!(bigdecimal_to_u256(&decimal).expect("Failed to convert"), U256::from(10).pow(U256::from(30)));
+}
+
+#[test]
+fn test_bigdecimal_rejects_fraction() {
+    let decimal = BigDecimal::from_str("1.5").expect("Failed to parse BigDecimal");
+    let err = bigdecimal_to_u256(&decimal).expect_err("fractional values must be rejected");
+    assert!(err.to_string().contains("fractional"));
+}
+
+#[test]
+fn test_bigdecimal_rejects_negative_and_overflow() {
+    let negative = BigDecimal::from_str("-1").expect("Failed to parse BigDecimal");
+    assert!(bigdecimal_to_u256(&negative).is_err());
+
+    let too_big = u256_to_bigdecimal(U256::MAX) + BigDecimal::from(1);
+    assert!(bigdecimal_to_u256(&too_big).is_err());
+}
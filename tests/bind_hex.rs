@@ -0,0 +1,108 @@
+//! Integration tests for binding hex address strings, directly or after validation
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{Address, address};
+use test_alloy_sqlx::{BindHex, InvalidAddress, bind_validated};
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE ethereum_addresses (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            address BINARY(20) NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_bind_hex_round_trip() {
+    let pool = setup_sqlite_test().await;
+
+    sqlx::query("INSERT INTO ethereum_addresses (address) VALUES (?)")
+        .bind(BindHex("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert address");
+
+    let row = sqlx::query("SELECT address FROM ethereum_addresses")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+
+    let decoded: Address = row.get("address");
+    assert_eq!(decoded, address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"));
+}
+
+#[tokio::test]
+async fn test_sqlite_bind_hex_invalid_errors_at_encode() {
+    let pool = setup_sqlite_test().await;
+
+    let err = sqlx::query("INSERT INTO ethereum_addresses (address) VALUES (?)")
+        .bind(BindHex("0xnot-an-address"))
+        .execute(&pool)
+        .await
+        .expect_err("invalid hex must fail to bind");
+    assert!(matches!(err, sqlx::Error::Encode(_)), "unexpected error: {err}");
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ethereum_addresses")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count rows");
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_bind_validated_accepts_valid_input() {
+    let expected = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    for input in [
+        "0x742D35CC6635C0532925A3b8D42cC72b5c2A9a1D",
+        "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d",
+        "0X742D35CC6635C0532925A3B8D42CC72B5C2A9A1D",
+        "742d35cc6635c0532925a3b8d42cc72b5c2a9a1d",
+    ] {
+        assert_eq!(bind_validated(input, true), Ok(expected), "{input}");
+    }
+}
+
+#[test]
+fn test_bind_validated_rejects_invalid_input() {
+    let short = "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a";
+    assert_eq!(bind_validated(short, false), Err(InvalidAddress::Length(short.to_string())));
+
+    let non_hex = "0x742d35cc6635c0532925a3b8d42cc72b5c2a9azz";
+    assert_eq!(bind_validated(non_hex, false), Err(InvalidAddress::NonHex(non_hex.to_string())));
+    let padded = " 0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d";
+    assert_eq!(bind_validated(padded, false), Err(InvalidAddress::NonHex(padded.to_string())));
+
+    // Mixed case, but not the EIP-55 casing; only rejected when verified
+    let bad_checksum = "0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d";
+    assert_eq!(bind_validated(bad_checksum, true), Err(InvalidAddress::Checksum(bad_checksum.to_string())));
+    assert!(bind_validated(bad_checksum, false).is_ok());
+}
+
+#[tokio::test]
+async fn test_sqlite_bind_validated_round_trip() {
+    let pool = setup_sqlite_test().await;
+    let addr = bind_validated("0x742D35CC6635C0532925A3b8D42cC72b5c2A9a1D", true).expect("valid address");
+
+    sqlx::query("INSERT INTO ethereum_addresses (address) VALUES (?)")
+        .bind(addr)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert address");
+
+    let decoded: Address = sqlx::query_scalar("SELECT address FROM ethereum_addresses")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+    assert_eq!(decoded, addr);
+}
@@ -0,0 +1,66 @@
+//! Integration tests for the `Bytes` decode helpers and `BytesHex`
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::Bytes;
+use test_alloy_sqlx::{BytesHex, decode_bytes_ref};
+
+#[tokio::test]
+async fn test_sqlite_decode_bytes_ref() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE blobs (id INTEGER PRIMARY KEY, data BLOB NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    let payload = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]);
+    sqlx::query("INSERT INTO blobs (data) VALUES (?)")
+        .bind(payload.to_vec())
+        .execute(&pool)
+        .await
+        .expect("Failed to insert blob");
+
+    let row = sqlx::query("SELECT data FROM blobs")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select blob");
+
+    assert_eq!(decode_bytes_ref(&row, "data").expect("Failed to decode"), payload.as_ref());
+    assert!(decode_bytes_ref(&row, "missing").is_err());
+}
+
+#[tokio::test]
+async fn test_sqlite_bytes_hex_round_trip() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE payloads (id INTEGER PRIMARY KEY, data TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    let payload = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef, 0x00, 0x01]);
+    sqlx::query("INSERT INTO payloads (data) VALUES (?), (?), (?), (?)")
+        .bind(BytesHex(payload.clone()))
+        .bind("DEADBEEF0001")
+        .bind("0X")
+        .bind("0xzz")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert payloads");
+
+    let rows = sqlx::query("SELECT data FROM payloads ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select payloads");
+
+    let raw: String = rows[0].get("data");
+    assert_eq!(raw, "0xdeadbeef0001");
+    assert_eq!(rows[0].get::<BytesHex, _>("data").0, payload);
+    assert_eq!(rows[1].get::<BytesHex, _>("data").0, payload);
+    assert_eq!(rows[2].get::<BytesHex, _>("data").0, Bytes::new());
+    assert!(rows[3].try_get::<BytesHex, _>("data").is_err());
+}
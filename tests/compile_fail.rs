@@ -0,0 +1,12 @@
+//! Compile tests for the types that can be bound and decoded
+//!
+//! Rejected types are covered by `compile_fail` doctests next to the types
+//! (`[u8; 20]` in the `address` module docs, decoding `BindHex`). sqlx's
+//! traits are foreign, so the compiler error can't carry a crate-specific
+//! hint, and a snapshot of it would pin the exact rustc and sqlx versions.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+}
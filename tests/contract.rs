@@ -0,0 +1,69 @@
+//! Tests for contract address derivation
+
+use sqlx::SqlitePool;
+use alloy_primitives::{Address, B256, address, b256, keccak256};
+use test_alloy_sqlx::{derive_create2_address, derive_create_address};
+
+#[test]
+fn test_derive_create_address_vectors() {
+    let deployer = address!("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+
+    assert_eq!(derive_create_address(deployer, 0), address!("0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"));
+    assert_eq!(derive_create_address(deployer, 1), address!("0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"));
+    assert_eq!(derive_create_address(deployer, 2), address!("0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91"));
+    assert_eq!(derive_create_address(deployer, 3), address!("0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"));
+}
+
+#[test]
+fn test_derive_create2_address_vectors() {
+    // Examples 0 and 2 from EIP-1014, both with init code 0x00
+    let init_code_hash = keccak256([0x00]);
+
+    assert_eq!(
+        derive_create2_address(Address::ZERO, B256::ZERO, init_code_hash),
+        address!("0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38")
+    );
+    assert_eq!(
+        derive_create2_address(
+            address!("0xdeadbeef00000000000000000000000000000000"),
+            b256!("0x000000000000000000000000feed000000000000000000000000000000000000"),
+            init_code_hash,
+        ),
+        address!("0xD04116cDd17beBE565EB2422F2497E06cC1C9833")
+    );
+}
+
+#[tokio::test]
+async fn test_sqlite_store_derived_address() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE deployments (
+            deployer BLOB NOT NULL,
+            nonce INTEGER NOT NULL,
+            contract BLOB NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    let deployer = address!("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+    sqlx::query("INSERT INTO deployments (deployer, nonce, contract) VALUES (?, ?, ?)")
+        .bind(deployer)
+        .bind(1i64)
+        .bind(derive_create_address(deployer, 1))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert deployment");
+
+    let contract: Address = sqlx::query_scalar("SELECT contract FROM deployments WHERE deployer = ? AND nonce = ?")
+        .bind(deployer)
+        .bind(1i64)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select deployment");
+    assert_eq!(contract, address!("0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"));
+}
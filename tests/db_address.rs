@@ -1,7 +1,7 @@
 //! Integration tests for database operations using sqlx and alloy_primitives
 
 use sqlx::{Row, SqlitePool, MySqlPool, PgPool};
-use alloy_primitives::{Address, address};
+use alloy_primitives::{Address, FixedBytes, address};
 
 // cargo test -- --test-threads=1
 #[tokio::test]
@@ -47,6 +47,63 @@ async fn test_sqlite_basic_operations() {
     assert_eq!(name, "Test Address");
 }
 
+#[tokio::test]
+async fn test_sqlite_blob_storage_and_byte_range() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE blob_addresses (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            address BLOB NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    let addrs = [
+        address!("0x0000000000000000000000000000000000000001"),
+        address!("0x10000000000000000000000000000000000000ff"),
+        address!("0x7fffffffffffffffffffffffffffffffffffffff"),
+        address!("0x8000000000000000000000000000000000000000"),
+        address!("0xffffffffffffffffffffffffffffffffffffffff"),
+    ];
+    for addr in &addrs {
+        sqlx::query("INSERT INTO blob_addresses (address) VALUES (?)")
+            .bind(addr)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert address");
+    }
+
+    // Stored as a 20-byte blob, not text
+    let storage: (String, i64) = sqlx::query_as("SELECT typeof(address), length(address) FROM blob_addresses LIMIT 1")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to inspect storage");
+    assert_eq!(storage, ("blob".to_string(), 20));
+
+    // Byte-wise range over the lower half of the address space
+    let lower: Vec<Address> = sqlx::query_scalar("SELECT address FROM blob_addresses WHERE address >= ? AND address < ? ORDER BY address")
+        .bind(Address::ZERO)
+        .bind(address!("0x8000000000000000000000000000000000000000"))
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to run range query");
+    assert_eq!(lower, addrs[..3].to_vec());
+
+    // A FixedBytes<20> with the same bytes binds and decodes identically
+    let fixed: FixedBytes<20> = FixedBytes::from(addrs[1].0);
+    let matched: FixedBytes<20> = sqlx::query_scalar("SELECT address FROM blob_addresses WHERE address = ?")
+        .bind(fixed)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to find address by FixedBytes<20>");
+    assert_eq!(Address::from(matched), addrs[1]);
+}
+
 // Helper function: setup MySQL connection and test table
 async fn setup_mysql_test() -> Option<MySqlPool> {
     // Try to connect to local MySQL, skip test if it fails
@@ -0,0 +1,116 @@
+//! Integration tests for strict EIP-55 checksummed address text
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::address;
+use test_alloy_sqlx::ChecksumAddress;
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE checksum_addresses (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            address VARCHAR(42) NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+async fn decode_stored(pool: &SqlitePool, text: &str) -> Result<ChecksumAddress, sqlx::Error> {
+    sqlx::query("DELETE FROM checksum_addresses")
+        .execute(pool)
+        .await
+        .expect("Failed to clear table");
+    sqlx::query("INSERT INTO checksum_addresses (address) VALUES (?)")
+        .bind(text)
+        .execute(pool)
+        .await
+        .expect("Failed to insert address");
+
+    let row = sqlx::query("SELECT address FROM checksum_addresses")
+        .fetch_one(pool)
+        .await
+        .expect("Failed to select address");
+    row.try_get("address")
+}
+
+#[tokio::test]
+async fn test_sqlite_checksum_address_round_trip() {
+    let pool = setup_sqlite_test().await;
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    sqlx::query("INSERT INTO checksum_addresses (address) VALUES (?)")
+        .bind(ChecksumAddress(test_addr))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert address");
+
+    let row = sqlx::query("SELECT address FROM checksum_addresses")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+
+    let raw: String = row.get("address");
+    assert_eq!(raw, test_addr.to_checksum(None));
+    let decoded: ChecksumAddress = row.get("address");
+    assert_eq!(decoded.0, test_addr);
+}
+
+#[tokio::test]
+async fn test_sqlite_checksum_address_valid_checksum_decodes() {
+    let pool = setup_sqlite_test().await;
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    let decoded = decode_stored(&pool, &test_addr.to_checksum(None))
+        .await
+        .expect("valid checksum must decode");
+    assert_eq!(decoded.0, test_addr);
+}
+
+#[tokio::test]
+async fn test_sqlite_checksum_address_invalid_mixed_case_errors() {
+    let pool = setup_sqlite_test().await;
+    let checksummed = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d").to_checksum(None);
+
+    // Flip the case of the first letter to break the checksum
+    let mut flipped = false;
+    let corrupted: String = checksummed
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if i >= 2 && !flipped && c.is_ascii_alphabetic() {
+                flipped = true;
+                if c.is_ascii_uppercase() { c.to_ascii_lowercase() } else { c.to_ascii_uppercase() }
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let err = decode_stored(&pool, &corrupted)
+        .await
+        .expect_err("bad checksum must not decode");
+    assert!(err.to_string().contains("checksum"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn test_sqlite_checksum_address_single_case_decodes() {
+    let pool = setup_sqlite_test().await;
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    let lower = decode_stored(&pool, "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d")
+        .await
+        .expect("lowercase must decode");
+    assert_eq!(lower.0, test_addr);
+
+    let upper = decode_stored(&pool, "0x742D35CC6635C0532925A3B8D42CC72B5C2A9A1D")
+        .await
+        .expect("uppercase must decode");
+    assert_eq!(upper.0, test_addr);
+}
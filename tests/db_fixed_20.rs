@@ -53,6 +53,47 @@ async fn test_sqlite_basic_operations() {
     assert_eq!(name, "Test Fixed Bytes");
 }
 
+#[tokio::test]
+async fn test_sqlite_null_vs_empty_blob() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE test_fixed_nullable (
+            id INTEGER PRIMARY KEY,
+            fixed_bytes BLOB
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    // NULL, zero-length blob, and a correct 20-byte blob
+    let test_fixed = convert_to_fixed_bytes(address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"));
+    sqlx::query("INSERT INTO test_fixed_nullable (id, fixed_bytes) VALUES (1, NULL), (2, X''), (3, ?)")
+        .bind(&test_fixed)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert fixed bytes");
+
+    let rows = sqlx::query("SELECT fixed_bytes FROM test_fixed_nullable ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select fixed bytes");
+
+    let null_value: Option<MyFixedBytes> = rows[0].get("fixed_bytes");
+    let empty_value = rows[1].try_get::<Option<MyFixedBytes>, _>("fixed_bytes");
+    let full_value = rows[2].try_get::<Option<MyFixedBytes>, _>("fixed_bytes");
+
+    assert_eq!(null_value, None);
+    assert!(empty_value.is_err(), "empty blob must not decode as FixedBytes<20>");
+    assert_eq!(full_value.expect("Failed to decode fixed bytes"), Some(test_fixed));
+
+    // Without Option, NULL is an error rather than a zero value
+    assert!(rows[0].try_get::<MyFixedBytes, _>("fixed_bytes").is_err());
+}
+
 // Helper function: setup MySQL connection and test table
 async fn setup_mysql_test() -> Option<MySqlPool> {
     // Try to connect to local MySQL, skip test if it fails
@@ -0,0 +1,72 @@
+//! Integration tests for `FixedBytes` stored little-endian
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{B256, b256};
+use test_alloy_sqlx::FixedBytesLe;
+
+const TEST_HASH: B256 = b256!("0x00000000000000000001a2b3c4d5e6f708090a0b0c0d0e0f1011121314151617");
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE legacy_blocks (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            hash BLOB NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_reads_legacy_le_hash() {
+    let pool = setup_sqlite_test().await;
+
+    // As written by the legacy system: the hash bytes reversed
+    let mut stored = TEST_HASH.to_vec();
+    stored.reverse();
+    sqlx::query("INSERT INTO legacy_blocks (hash) VALUES (?)")
+        .bind(stored)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert hash");
+
+    let row = sqlx::query("SELECT hash FROM legacy_blocks")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select hash");
+
+    let decoded: FixedBytesLe<32> = row.get("hash");
+    assert_eq!(decoded.0, TEST_HASH);
+}
+
+#[tokio::test]
+async fn test_sqlite_fixed_bytes_le_round_trip() {
+    let pool = setup_sqlite_test().await;
+
+    sqlx::query("INSERT INTO legacy_blocks (hash) VALUES (?), (?)")
+        .bind(FixedBytesLe(TEST_HASH))
+        .bind(vec![0x17u8; 31])
+        .execute(&pool)
+        .await
+        .expect("Failed to insert hashes");
+
+    let rows = sqlx::query("SELECT hash FROM legacy_blocks ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select hashes");
+
+    let raw: Vec<u8> = rows[0].get("hash");
+    assert_eq!(raw[0], 0x17);
+    assert_eq!(raw[31], 0x00);
+    assert_eq!(rows[0].get::<FixedBytesLe<32>, _>("hash").0, TEST_HASH);
+    // The plain codec sees the reversed bytes
+    assert_ne!(rows[0].get::<B256, _>("hash"), TEST_HASH);
+    assert!(rows[1].try_get::<FixedBytesLe<32>, _>("hash").is_err());
+}
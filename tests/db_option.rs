@@ -0,0 +1,81 @@
+//! Integration tests for binding optional primitives as SQL NULL
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{Address, B256, U256, address};
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE nullable_primitives (
+            id INTEGER PRIMARY KEY,
+            address BINARY(20),
+            tx_hash BINARY(32),
+            amount BINARY(32)
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_none_binds_null() {
+    let pool = setup_sqlite_test().await;
+
+    sqlx::query("INSERT INTO nullable_primitives (id, address, tx_hash, amount) VALUES (1, ?, ?, ?)")
+        .bind(None::<Address>)
+        .bind(None::<B256>)
+        .bind(None::<U256>)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert NULLs");
+
+    let null_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM nullable_primitives
+         WHERE address IS NULL AND tx_hash IS NULL AND amount IS NULL"
+    )
+    .fetch_one(&pool)
+    .await
+    .expect("Failed to count NULL rows");
+    assert_eq!(null_count, 1);
+
+    let row = sqlx::query("SELECT address, tx_hash, amount FROM nullable_primitives")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select row");
+    assert_eq!(row.get::<Option<Address>, _>("address"), None);
+    assert_eq!(row.get::<Option<B256>, _>("tx_hash"), None);
+    assert_eq!(row.get::<Option<U256>, _>("amount"), None);
+}
+
+#[tokio::test]
+async fn test_sqlite_option_ref_binds() {
+    let pool = setup_sqlite_test().await;
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    for (id, addr) in [(1i64, Some(&test_addr)), (2, None)] {
+        sqlx::query("INSERT INTO nullable_primitives (id, address) VALUES (?, ?)")
+            .bind(id)
+            .bind(addr)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert address");
+    }
+
+    let null_ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM nullable_primitives WHERE address IS NULL")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select NULL addresses");
+    assert_eq!(null_ids, vec![2]);
+
+    let stored: Option<Address> = sqlx::query_scalar("SELECT address FROM nullable_primitives WHERE id = 1")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+    assert_eq!(stored, Some(test_addr));
+}
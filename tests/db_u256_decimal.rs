@@ -0,0 +1,118 @@
+//! Integration tests for `U256` stored as decimal text, plain and zero-padded
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::U256;
+use test_alloy_sqlx::{U256_PADDED_LEN, U256Decimal, U256PaddedText};
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE amounts (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            amount TEXT NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_u256_decimal_round_trip() {
+    let pool = setup_sqlite_test().await;
+    let values = [U256::ZERO, U256::MAX];
+
+    for value in &values {
+        sqlx::query("INSERT INTO amounts (amount) VALUES (?)")
+            .bind(U256Decimal(*value))
+            .execute(&pool)
+            .await
+            .expect("Failed to insert amount");
+    }
+
+    let rows = sqlx::query("SELECT amount FROM amounts ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select amounts");
+
+    let raw_max: String = rows[1].get("amount");
+    assert_eq!(raw_max, "115792089237316195423570985008687907853269984665640564039457584007913129639935");
+
+    for (row, value) in rows.iter().zip(&values) {
+        let amount: U256Decimal = row.get("amount");
+        assert_eq!(amount.0, *value);
+    }
+}
+
+#[tokio::test]
+async fn test_sqlite_u256_decimal_rejects_non_numeric() {
+    let pool = setup_sqlite_test().await;
+
+    sqlx::query("INSERT INTO amounts (amount) VALUES ('12abc'), ('-5')")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert amounts");
+
+    let rows = sqlx::query("SELECT amount FROM amounts ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select amounts");
+
+    for row in &rows {
+        let err = row
+            .try_get::<U256Decimal, _>("amount")
+            .expect_err("non-numeric text must not decode")
+            .to_string();
+        assert!(err.contains("not a non-negative integer"), "unexpected error: {err}");
+    }
+}
+
+#[tokio::test]
+async fn test_sqlite_u256_padded_text_sorts_numerically() {
+    let pool = setup_sqlite_test().await;
+
+    // Shuffled, with values whose plain decimal text would sort wrongly
+    let values = [
+        U256::from(100),
+        U256::MAX,
+        U256::from(9),
+        U256::ZERO,
+        U256::from(10).pow(U256::from(40)),
+        U256::from(25),
+        U256::from(u64::MAX),
+    ];
+    for value in values {
+        sqlx::query("INSERT INTO amounts (amount) VALUES (?)")
+            .bind(U256PaddedText(value))
+            .execute(&pool)
+            .await
+            .expect("Failed to insert amount");
+    }
+
+    let rows = sqlx::query("SELECT amount FROM amounts ORDER BY amount")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select amounts");
+
+    let raw: String = rows[0].get("amount");
+    assert_eq!(raw.len(), U256_PADDED_LEN);
+    assert_eq!(raw, "0".repeat(U256_PADDED_LEN));
+
+    let sorted: Vec<U256> = rows.iter().map(|row| row.get::<U256PaddedText, _>("amount").0).collect();
+    let mut expected = values.to_vec();
+    expected.sort();
+    assert_eq!(sorted, expected);
+
+    // Range predicates compare the same way
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM amounts WHERE amount > ?")
+        .bind(U256PaddedText(U256::from(25)))
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count amounts");
+    assert_eq!(count, 4);
+}
@@ -0,0 +1,152 @@
+//! Integration tests for fixed-width `Uint` blobs
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{U256, U512, Uint};
+use test_alloy_sqlx::{SmallU256, U96Blob, U512Blob};
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE commitments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            scalar BLOB NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_u512_round_trip() {
+    let pool = setup_sqlite_test().await;
+    let value = U512::MAX - (U512::from(0xdead_beef_u64) << 300);
+
+    sqlx::query("INSERT INTO commitments (scalar) VALUES (?)")
+        .bind(U512Blob::from(value))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert scalar");
+
+    let row = sqlx::query("SELECT scalar FROM commitments")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select scalar");
+
+    let raw: Vec<u8> = row.get("scalar");
+    assert_eq!(raw, value.to_be_bytes::<64>().to_vec());
+    let decoded: U512Blob = row.get("scalar");
+    assert_eq!(decoded.0, value);
+}
+
+#[tokio::test]
+async fn test_sqlite_u512_wrong_length_fails() {
+    let pool = setup_sqlite_test().await;
+
+    // A 32-byte value would fit numerically, but isn't a 512-bit column value
+    sqlx::query("INSERT INTO commitments (scalar) VALUES (?)")
+        .bind(vec![0x01u8; 32])
+        .execute(&pool)
+        .await
+        .expect("Failed to insert scalar");
+
+    let row = sqlx::query("SELECT scalar FROM commitments")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select scalar");
+
+    let err = row
+        .try_get::<U512Blob, _>("scalar")
+        .expect_err("short blob must not decode");
+    assert!(err.to_string().contains("expected 64 bytes, got 32"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn test_sqlite_u96_max_round_trip() {
+    let pool = setup_sqlite_test().await;
+    let max = Uint::<96, 2>::MAX;
+
+    sqlx::query("INSERT INTO commitments (scalar) VALUES (?)")
+        .bind(U96Blob::from(max))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert value");
+
+    let row = sqlx::query("SELECT scalar FROM commitments")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select value");
+
+    let raw: Vec<u8> = row.get("scalar");
+    assert_eq!(raw, vec![0xff; 12]);
+    let decoded: U96Blob = row.get("scalar");
+    assert_eq!(decoded.0, max);
+}
+
+#[tokio::test]
+async fn test_sqlite_u96_overflow_fails() {
+    let pool = setup_sqlite_test().await;
+
+    // 2^96 needs a 13th byte
+    let mut too_big = vec![0u8; 13];
+    too_big[0] = 0x01;
+    sqlx::query("INSERT INTO commitments (scalar) VALUES (?)")
+        .bind(too_big)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert value");
+
+    let row = sqlx::query("SELECT scalar FROM commitments")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select value");
+
+    let err = row
+        .try_get::<U96Blob, _>("scalar")
+        .expect_err("13-byte value must not decode as Uint<96>");
+    assert!(err.to_string().contains("expected 12 bytes, got 13"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn test_sqlite_small_u256_narrowing() {
+    let pool = setup_sqlite_test().await;
+
+    for value in [U256::from(42), U256::from(i64::MAX), U256::from(i64::MAX) + U256::from(1), U256::MAX] {
+        sqlx::query("INSERT INTO commitments (scalar) VALUES (?)")
+            .bind(value)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert value");
+    }
+
+    let rows = sqlx::query("SELECT scalar FROM commitments ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select values");
+
+    assert_eq!(rows[0].get::<SmallU256, _>("scalar"), SmallU256(42));
+    assert_eq!(rows[1].get::<SmallU256, _>("scalar"), SmallU256(i64::MAX));
+    for row in &rows[2..] {
+        let err = row
+            .try_get::<SmallU256, _>("scalar")
+            .expect_err("values above i64::MAX must not decode");
+        assert!(err.to_string().contains("does not fit in i64"), "unexpected error: {err}");
+    }
+}
+
+#[tokio::test]
+async fn test_sqlite_small_u256_rejects_negative_encode() {
+    let pool = setup_sqlite_test().await;
+
+    let err = sqlx::query("INSERT INTO commitments (scalar) VALUES (?)")
+        .bind(SmallU256(-1))
+        .execute(&pool)
+        .await
+        .expect_err("negative values must not encode");
+    assert!(matches!(err, sqlx::Error::Encode(_)), "unexpected error: {err}");
+}
@@ -0,0 +1,29 @@
+//! Tests for the encoding descriptions
+
+use alloy_primitives::{B256, U256, address};
+use test_alloy_sqlx::{Dialect, describe_encoding};
+
+#[test]
+fn test_describe_address_on_all_backends() {
+    let addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    assert_eq!(
+        describe_encoding(&addr, Dialect::Postgres),
+        "Postgres: BYTEA, 20 bytes, big-endian, 0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d"
+    );
+    assert_eq!(
+        describe_encoding(&addr, Dialect::MySql),
+        "MySQL: BINARY(20), 20 bytes, big-endian, 0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d"
+    );
+    assert_eq!(
+        describe_encoding(&addr, Dialect::Sqlite),
+        "SQLite: BLOB, 20 bytes, big-endian, 0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d"
+    );
+}
+
+#[test]
+fn test_describe_u256_and_b256() {
+    let expected = format!("MySQL: BINARY(32), 32 bytes, big-endian, 0x{}01", "00".repeat(31));
+    assert_eq!(describe_encoding(&U256::from(1), Dialect::MySql), expected);
+    assert_eq!(describe_encoding(&B256::with_last_byte(1), Dialect::MySql), expected);
+}
@@ -0,0 +1,71 @@
+//! Integration tests for streaming CSV exports
+
+use sqlx::SqlitePool;
+use alloy_primitives::{U256, address};
+use test_alloy_sqlx::export_addresses_csv;
+
+#[tokio::test]
+async fn test_sqlite_export_addresses_csv() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE balances (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            address BINARY(20) NOT NULL,
+            balance BINARY(32) NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    for (addr, balance) in [
+        (address!("0xdac17f958d2ee523a2206206994597c13d831ec7"), U256::from(10).pow(U256::from(24))),
+        (address!("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"), U256::ZERO),
+        (address!("0x0000000000000000000000000000000000000001"), U256::MAX),
+    ] {
+        sqlx::query("INSERT INTO balances (address, balance) VALUES (?, ?)")
+            .bind(addr)
+            .bind(balance)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert balance");
+    }
+
+    let mut csv = Vec::new();
+    let written = export_addresses_csv(&pool, "balances", &mut csv)
+        .await
+        .expect("Failed to export balances");
+
+    assert_eq!(written, 3);
+    assert_eq!(
+        String::from_utf8(csv).expect("CSV is UTF-8"),
+        "address,balance\n\
+         0x0000000000000000000000000000000000000001,\
+         115792089237316195423570985008687907853269984665640564039457584007913129639935\n\
+         0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,0\n\
+         0xdAC17F958D2ee523a2206206994597C13D831ec7,1000000000000000000000000\n"
+    );
+}
+
+#[tokio::test]
+async fn test_sqlite_export_empty_table() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE balances (address BLOB NOT NULL, balance BLOB NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    let mut csv = Vec::new();
+    let written = export_addresses_csv(&pool, "balances", &mut csv)
+        .await
+        .expect("Failed to export balances");
+
+    assert_eq!(written, 0);
+    assert_eq!(csv, b"address,balance\n");
+}
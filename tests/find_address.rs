@@ -0,0 +1,100 @@
+//! Integration tests for `find_address`, `get_optional_address` and the `FindResult` variants
+
+use sqlx::SqlitePool;
+use alloy_primitives::address;
+use test_alloy_sqlx::{FindResult, find_address, find_address_result, get_optional_address};
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE wallets (
+            id INTEGER PRIMARY KEY,
+            address BINARY(20)
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    sqlx::query("INSERT INTO wallets (id, address) VALUES (1, ?), (2, NULL)")
+        .bind(&address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert wallets");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_find_address_found() {
+    let pool = setup_sqlite_test().await;
+    let expected = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    let result = find_address_result(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(1))
+        .await
+        .expect("Failed to find address");
+    assert_eq!(result, FindResult::Found(expected));
+
+    let found = find_address(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(1))
+        .await
+        .expect("Failed to find address");
+    assert_eq!(found, Some(expected));
+}
+
+#[tokio::test]
+async fn test_sqlite_find_address_null_value() {
+    let pool = setup_sqlite_test().await;
+
+    let result = find_address_result(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(2))
+        .await
+        .expect("Failed to find address");
+    assert_eq!(result, FindResult::NullValue);
+
+    let found = find_address(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(2))
+        .await
+        .expect("Failed to find address");
+    assert_eq!(found, None);
+}
+
+#[tokio::test]
+async fn test_sqlite_find_address_no_row() {
+    let pool = setup_sqlite_test().await;
+
+    let result = find_address_result(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(3))
+        .await
+        .expect("Failed to find address");
+    assert_eq!(result, FindResult::NoRow);
+
+    let found = find_address(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(3))
+        .await
+        .expect("Failed to find address");
+    assert_eq!(found, None);
+}
+
+#[tokio::test]
+async fn test_sqlite_get_optional_address_found() {
+    let pool = setup_sqlite_test().await;
+    let expected = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    let found = get_optional_address(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(1))
+        .await
+        .expect("Failed to get address");
+    assert_eq!(found, Some(expected));
+}
+
+#[tokio::test]
+async fn test_sqlite_get_optional_address_not_found() {
+    let pool = setup_sqlite_test().await;
+
+    let found = get_optional_address(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(3))
+        .await
+        .expect("Failed to get address");
+    assert_eq!(found, None);
+
+    // A NULL column is an error rather than a missing row
+    let null = get_optional_address(&pool, sqlx::query("SELECT address FROM wallets WHERE id = ?").bind(2)).await;
+    assert!(null.is_err());
+}
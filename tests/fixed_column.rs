@@ -0,0 +1,68 @@
+//! Tests for width-checked `FixedColumn` binds
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{Address, FixedBytes, address};
+use test_alloy_sqlx::{ColumnWidthMismatch, Dialect, FixedColumn};
+
+#[test]
+fn test_fixed_column_type_and_slice_check() {
+    assert_eq!(FixedColumn::<20>::column_type(Dialect::MySql), "BINARY(20)");
+    assert_eq!(FixedColumn::<32>::column_type(Dialect::Postgres), "BYTEA");
+
+    let addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    let column = FixedColumn::<20>::try_from_slice(addr.as_slice()).expect("20 bytes fit a 20-byte column");
+    assert_eq!(column.0, addr.0);
+
+    let err = FixedColumn::<20>::try_from_slice(&[0u8; 32]).expect_err("32 bytes must not fit");
+    assert_eq!(err, ColumnWidthMismatch { expected: 20, actual: 32 });
+    assert_eq!(err.to_string(), "32 bytes don't fit a 20-byte column");
+}
+
+#[tokio::test]
+async fn test_sqlite_fixed_column_round_trip() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(&format!(
+        "CREATE TABLE owners (owner {} NOT NULL)",
+        FixedColumn::<20>::column_type(Dialect::Sqlite)
+    ))
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    let addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    sqlx::query("INSERT INTO owners (owner) VALUES (?)")
+        .bind(FixedColumn::from(addr.0))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert owner");
+
+    let stored: FixedColumn<20> = sqlx::query_scalar("SELECT owner FROM owners")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select owner");
+    assert_eq!(Address::from(FixedBytes::from(stored)), addr);
+}
+
+#[tokio::test]
+async fn test_sqlite_fixed_column_rejects_other_width() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    // A 32-byte hash stored where a 20-byte column was expected
+    let row = sqlx::query("SELECT zeroblob(32) AS owner")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select blob");
+    let err = row.try_get::<FixedColumn<20>, _>("owner").expect_err("32 bytes must not decode");
+    let sqlx::Error::ColumnDecode { source, .. } = err else {
+        panic!("expected a column decode error, got {err:?}");
+    };
+    assert_eq!(
+        source.downcast_ref::<ColumnWidthMismatch>(),
+        Some(&ColumnWidthMismatch { expected: 20, actual: 32 })
+    );
+}
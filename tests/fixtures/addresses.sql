@@ -0,0 +1,4 @@
+-- Seeds two well-known addresses as 20-byte blob literals.
+INSERT INTO ethereum_addresses (address, name) VALUES
+    (X'742d35cc6635c0532925a3b8d42cc72b5c2a9a1d', 'Test User'),
+    (X'00000000219ab540356cbb839cbe05303d7705fa', 'Deposit Contract');
@@ -0,0 +1,7 @@
+-- Address-keyed table used by the `#[sqlx::test]` examples.
+-- Addresses are 20-byte blobs, matching the binary `Address` codec.
+CREATE TABLE ethereum_addresses (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    address BLOB NOT NULL UNIQUE,
+    name TEXT NOT NULL
+);
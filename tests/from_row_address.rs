@@ -1,6 +1,7 @@
-use sqlx::FromRow;
+use sqlx::{FromRow, Row, sqlite::SqliteRow};
 use alloy_primitives::{Address, address};
 use serde::{Deserialize, Serialize};
+use test_alloy_sqlx::{split_address, try_get_split_address};
 use sqlx::{SqlitePool, MySqlPool, PgPool};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, FromRow)]
@@ -163,3 +164,120 @@ async fn test_postgres_from_row() {
     assert_eq!(user_info.name, user_info_from_db.name);
     assert_eq!(user_info.address, user_info_from_db.address);
 }
+
+/// Column names differ from field names; `owner` may be absent from the
+/// result set and then falls back to `Address::ZERO`.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct WalletRow {
+    #[sqlx(rename = "wallet_address")]
+    pub address: Address,
+    #[sqlx(default)]
+    pub owner: Address,
+}
+
+#[tokio::test]
+async fn test_sqlite_from_row_rename_and_default() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE wallets (
+            wallet_address BLOB PRIMARY KEY,
+            owner BLOB
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    let wallet = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    let owner = address!("0x00000000219ab540356cBB839Cbe05303d7705Fa");
+    sqlx::query("INSERT INTO wallets (wallet_address, owner) VALUES (?, ?)")
+        .bind(&wallet)
+        .bind(&owner)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert wallet");
+
+    let full: WalletRow = sqlx::query_as("SELECT wallet_address, owner FROM wallets WHERE wallet_address = ?")
+        .bind(&wallet)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to fetch wallet");
+    assert_eq!(full, WalletRow { address: wallet, owner });
+
+    let without_owner: WalletRow = sqlx::query_as("SELECT wallet_address FROM wallets WHERE wallet_address = ?")
+        .bind(&wallet)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to fetch wallet without owner");
+    assert_eq!(without_owner, WalletRow { address: wallet, owner: Address::ZERO });
+}
+
+// Warehouse schema with the address split into a BIGINT prefix and a 12-byte suffix
+#[derive(Debug, PartialEq)]
+pub struct SplitHolder {
+    pub address: Address,
+    pub name: String,
+}
+
+impl<'r> FromRow<'r, SqliteRow> for SplitHolder {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            address: try_get_split_address(row, "addr_prefix", "addr_suffix")?,
+            name: row.try_get("name")?,
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_sqlite_from_row_split_address() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE holders (
+            addr_prefix BIGINT NOT NULL,
+            addr_suffix BLOB NOT NULL,
+            name TEXT NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    // A high first byte makes the prefix negative
+    let holders = [
+        address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"),
+        address!("0xdAC17F958D2ee523a2206206994597C13D831ec7"),
+    ];
+    for addr in holders {
+        let (prefix, suffix) = split_address(addr);
+        sqlx::query("INSERT INTO holders (addr_prefix, addr_suffix, name) VALUES (?, ?, ?)")
+            .bind(prefix)
+            .bind(suffix.to_vec())
+            .bind("Holder")
+            .execute(&pool)
+            .await
+            .expect("Failed to insert holder");
+    }
+    sqlx::query("INSERT INTO holders (addr_prefix, addr_suffix, name) VALUES (1, x'0102', 'Broken')")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert holder");
+
+    let rows: Vec<SplitHolder> = sqlx::query_as("SELECT * FROM holders WHERE name = 'Holder' ORDER BY rowid")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to fetch holders");
+    let addresses: Vec<Address> = rows.iter().map(|row| row.address).collect();
+    assert_eq!(addresses, holders);
+    assert!(split_address(holders[1]).0 < 0);
+
+    let broken = sqlx::query_as::<_, SplitHolder>("SELECT * FROM holders WHERE name = 'Broken'")
+        .fetch_one(&pool)
+        .await;
+    assert!(broken.is_err());
+}
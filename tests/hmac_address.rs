@@ -0,0 +1,96 @@
+//! Integration tests for HMAC-tagged addresses
+#![cfg(feature = "hmac")]
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::address;
+use test_alloy_sqlx::{HmacAddress, HmacKey, HmacMismatch};
+
+struct RegistryKey;
+
+impl HmacKey for RegistryKey {
+    fn key() -> &'static [u8] {
+        b"registry-secret-key"
+    }
+}
+
+struct OtherKey;
+
+impl HmacKey for OtherKey {
+    fn key() -> &'static [u8] {
+        b"other-key"
+    }
+}
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE registry (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            tagged_address BLOB NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_hmac_address_round_trip() {
+    let pool = setup_sqlite_test().await;
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    sqlx::query("INSERT INTO registry (tagged_address) VALUES (?)")
+        .bind(HmacAddress::<RegistryKey>::new(test_addr))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert address");
+
+    let row = sqlx::query("SELECT tagged_address FROM registry")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+
+    let tagged: HmacAddress<RegistryKey> = row.get("tagged_address");
+    assert_eq!(tagged.address(), test_addr);
+
+    // A different key must not verify
+    let err = row
+        .try_get::<HmacAddress<OtherKey>, _>("tagged_address")
+        .expect_err("a tag under another key must not decode");
+    let sqlx::Error::ColumnDecode { source, .. } = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(source.downcast_ref::<HmacMismatch>(), Some(&HmacMismatch { address: test_addr }));
+}
+
+#[tokio::test]
+async fn test_sqlite_hmac_address_tampered() {
+    let pool = setup_sqlite_test().await;
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    // Flip one address byte in the stored blob
+    let mut blob = HmacAddress::<RegistryKey>::new(test_addr).to_bytes();
+    blob[0] ^= 0x01;
+
+    sqlx::query("INSERT INTO registry (tagged_address) VALUES (?)")
+        .bind(blob.to_vec())
+        .execute(&pool)
+        .await
+        .expect("Failed to insert blob");
+
+    let row = sqlx::query("SELECT tagged_address FROM registry")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+
+    // The tampered row fails at decode time
+    assert!(row.try_get::<HmacAddress<RegistryKey>, _>("tagged_address").is_err());
+
+    // Truncated blobs are rejected too
+    assert!(HmacAddress::<RegistryKey>::from_bytes(&blob[..40]).is_err());
+}
@@ -0,0 +1,80 @@
+//! Integration tests for generic key lookups
+
+use sqlx::{FromRow, SqlitePool};
+use alloy_primitives::{Address, B256, address, b256};
+use test_alloy_sqlx::get_by_key;
+
+#[derive(Debug, PartialEq, FromRow)]
+struct Account {
+    address: Address,
+    name: String,
+}
+
+#[derive(Debug, PartialEq, FromRow)]
+struct Receipt {
+    tx_hash: B256,
+    status: i64,
+}
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE accounts (address BLOB PRIMARY KEY, name TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create accounts table");
+    sqlx::query("CREATE TABLE receipts (tx_hash BLOB PRIMARY KEY, status INTEGER NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create receipts table");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_get_by_address_key() {
+    let pool = setup_sqlite_test().await;
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    sqlx::query("INSERT INTO accounts (address, name) VALUES (?, ?)")
+        .bind(&test_addr)
+        .bind("Alice")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert account");
+
+    let found: Option<Account> = get_by_key(&pool, "accounts", "address", test_addr)
+        .await
+        .expect("Failed to look up account");
+    assert_eq!(found, Some(Account { address: test_addr, name: "Alice".to_string() }));
+
+    let missing: Option<Account> = get_by_key(&pool, "accounts", "address", Address::ZERO)
+        .await
+        .expect("Failed to look up account");
+    assert_eq!(missing, None);
+}
+
+#[tokio::test]
+async fn test_sqlite_get_by_b256_key() {
+    let pool = setup_sqlite_test().await;
+    let tx_hash = b256!("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+    sqlx::query("INSERT INTO receipts (tx_hash, status) VALUES (?, ?)")
+        .bind(&tx_hash)
+        .bind(1i64)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert receipt");
+
+    let found: Option<Receipt> = get_by_key(&pool, "receipts", "tx_hash", tx_hash)
+        .await
+        .expect("Failed to look up receipt");
+    assert_eq!(found, Some(Receipt { tx_hash, status: 1 }));
+
+    let missing: Option<Receipt> = get_by_key(&pool, "receipts", "tx_hash", B256::ZERO)
+        .await
+        .expect("Failed to look up receipt");
+    assert_eq!(missing, None);
+}
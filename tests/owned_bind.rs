@@ -0,0 +1,50 @@
+//! Integration tests for binding owned primitives inside spawned tasks
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{Address, B256, U256, address, keccak256};
+
+#[tokio::test]
+async fn test_sqlite_bind_owned_values_in_spawned_task() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE transfers (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            sender BLOB NOT NULL,
+            tx_hash BLOB NOT NULL,
+            amount BLOB NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    let sender = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    let tx_hash = keccak256("transfer");
+    let amount = U256::from(1_000_000_000_000_000_000u128);
+
+    // Everything is moved into the task and bound by value
+    let task_pool = pool.clone();
+    tokio::spawn(async move {
+        sqlx::query("INSERT INTO transfers (sender, tx_hash, amount) VALUES (?, ?, ?)")
+            .bind(sender)
+            .bind(tx_hash)
+            .bind(amount)
+            .execute(&task_pool)
+            .await
+    })
+    .await
+    .expect("Task panicked")
+    .expect("Failed to insert transfer");
+
+    let row = sqlx::query("SELECT sender, tx_hash, amount FROM transfers")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select transfer");
+
+    assert_eq!(row.get::<Address, _>("sender"), sender);
+    assert_eq!(row.get::<B256, _>("tx_hash"), tx_hash);
+    assert_eq!(row.get::<U256, _>("amount"), amount);
+}
@@ -0,0 +1,31 @@
+//! Tests pinning the Postgres parameter types reported by address codecs
+
+use sqlx::{Postgres, Type, TypeInfo};
+use alloy_primitives::Address;
+use test_alloy_sqlx::{AddressText, BindHex, ChecksumAddress, FlexAddress};
+
+#[test]
+fn test_address_type_info_is_bytea() {
+    let info = <Address as Type<Postgres>>::type_info();
+    assert_eq!(info.name(), "BYTEA");
+    assert_eq!(info, <Address as Type<Postgres>>::type_info());
+}
+
+#[test]
+fn test_binary_bind_sites_share_type_info() {
+    let info = <Address as Type<Postgres>>::type_info();
+
+    // By value, by reference, from hex text and through FlexAddress
+    assert_eq!(<&Address as Type<Postgres>>::type_info(), info);
+    assert_eq!(<BindHex<'_> as Type<Postgres>>::type_info(), info);
+    assert_eq!(<FlexAddress as Type<Postgres>>::type_info(), info);
+    assert_eq!(<Vec<u8> as Type<Postgres>>::type_info(), info);
+}
+
+#[test]
+fn test_text_bind_sites_share_type_info() {
+    let info = <AddressText as Type<Postgres>>::type_info();
+    assert_eq!(info.name(), "TEXT");
+    assert_eq!(<ChecksumAddress as Type<Postgres>>::type_info(), info);
+    assert_ne!(info, <Address as Type<Postgres>>::type_info());
+}
@@ -0,0 +1,54 @@
+//! Tests for borrowed primitives in generic `Type`/`Encode` bounds
+//!
+//! sqlx provides blanket `Type` and `Encode` impls for `&T`, so `&B256`,
+//! `&Address` and `&U256` satisfy the same bounds as the owned values.
+
+use sqlx::{Encode, Sqlite, SqlitePool, Type};
+use alloy_primitives::{Address, B256, U256, address, keccak256};
+
+async fn store<'q, T>(pool: &SqlitePool, value: T)
+where
+    T: Encode<'q, Sqlite> + Type<Sqlite> + 'q,
+{
+    sqlx::query("INSERT INTO blobs (value) VALUES (?)")
+        .bind(value)
+        .execute(pool)
+        .await
+        .expect("Failed to store value");
+}
+
+#[tokio::test]
+async fn test_sqlite_store_owned_and_borrowed() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE blobs (id INTEGER PRIMARY KEY AUTOINCREMENT, value BLOB NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    let hash: B256 = keccak256("reference");
+    let addr: Address = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+    let amount = U256::from(42u64);
+
+    store(&pool, hash).await;
+    store(&pool, &hash).await;
+    store(&pool, addr).await;
+    store(&pool, &addr).await;
+    store(&pool, amount).await;
+    store(&pool, &amount).await;
+
+    let values: Vec<Vec<u8>> = sqlx::query_scalar("SELECT value FROM blobs ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select values");
+
+    // Borrowed binds encode exactly like their owned counterparts
+    assert_eq!(values[0], hash.to_vec());
+    assert_eq!(values[1], values[0]);
+    assert_eq!(values[2], addr.to_vec());
+    assert_eq!(values[3], values[2]);
+    assert_eq!(values[4], amount.to_be_bytes::<32>().to_vec());
+    assert_eq!(values[5], values[4]);
+}
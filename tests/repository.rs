@@ -0,0 +1,74 @@
+//! Integration tests for the `eth_repository!` macro
+
+use sqlx::{FromRow, SqlitePool};
+use alloy_primitives::{Address, address};
+use test_alloy_sqlx::eth_repository;
+
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct UserInfo {
+    pub id: Option<i64>,
+    pub address: Address,
+    pub name: String,
+}
+
+eth_repository! {
+    pub struct UserRepo for UserInfo in "users" {
+        name: String,
+    }
+}
+
+async fn setup_sqlite_test() -> UserRepo<sqlx::Sqlite> {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE users (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            address BLOB NOT NULL UNIQUE,
+            name TEXT NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    UserRepo::new(pool)
+}
+
+fn user(address: Address, name: &str) -> UserInfo {
+    UserInfo { id: None, address, name: name.to_string() }
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_crud() {
+    let repo = setup_sqlite_test().await;
+    let alice = user(address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"), "Alice");
+    let bob = user(address!("0x00000000219ab540356cBB839Cbe05303d7705Fa"), "Bob");
+
+    repo.insert(&alice).await.expect("Failed to insert Alice");
+    repo.insert(&bob).await.expect("Failed to insert Bob");
+
+    let fetched = repo
+        .get_by_address(alice.address)
+        .await
+        .expect("Failed to fetch Alice")
+        .expect("Alice should exist");
+    assert!(fetched.id.is_some());
+    assert_eq!(fetched.address, alice.address);
+    assert_eq!(fetched.name, "Alice");
+
+    let listed: Vec<Address> = repo
+        .list()
+        .await
+        .expect("Failed to list users")
+        .into_iter()
+        .map(|u| u.address)
+        .collect();
+    assert_eq!(listed, vec![bob.address, alice.address]);
+
+    assert!(repo.delete_by_address(alice.address).await.expect("Failed to delete Alice"));
+    assert!(!repo.delete_by_address(alice.address).await.expect("Failed to delete Alice twice"));
+    assert_eq!(repo.get_by_address(alice.address).await.expect("Failed to fetch Alice"), None);
+    assert_eq!(repo.list().await.expect("Failed to list users").len(), 1);
+}
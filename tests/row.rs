@@ -0,0 +1,149 @@
+//! Integration tests for the row accessor helpers
+
+use sqlx::{FromRow, Row, SqlitePool};
+use alloy_primitives::{Address, address};
+use test_alloy_sqlx::{fetch_all_lenient, try_get_primitive};
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE accounts (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            owner BINARY(20) NOT NULL,
+            nickname TEXT NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    sqlx::query("INSERT INTO accounts (owner, nickname) VALUES (?, ?)")
+        .bind(&address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"))
+        .bind("alice")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert account");
+
+    pool
+}
+
+#[tokio::test]
+async fn test_sqlite_try_get_primitive_ok() {
+    let pool = setup_sqlite_test().await;
+    let row = sqlx::query("SELECT owner, nickname FROM accounts")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select account");
+
+    let owner: Address = try_get_primitive(&row, "owner").expect("Failed to decode owner");
+    assert_eq!(owner, address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"));
+}
+
+#[tokio::test]
+async fn test_sqlite_try_get_primitive_names_column() {
+    let pool = setup_sqlite_test().await;
+    let row = sqlx::query("SELECT owner, nickname FROM accounts")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select account");
+
+    // A text column read as an Address
+    let err = try_get_primitive::<_, Address>(&row, "nickname")
+        .expect_err("text column must not decode as Address")
+        .to_string();
+    assert!(err.contains("nickname"), "unexpected error: {err}");
+    assert!(err.contains("Address"), "unexpected error: {err}");
+    assert_eq!(err.matches("error occurred while decoding").count(), 1, "unexpected error: {err}");
+
+    // Errors other than decode failures keep their kind
+    let err = try_get_primitive::<_, Address>(&row, "missing").expect_err("missing column must not decode");
+    assert!(matches!(&err, sqlx::Error::ColumnNotFound(col) if col == "missing"), "unexpected error: {err}");
+}
+
+#[tokio::test]
+async fn test_sqlite_get_address_by_position() {
+    let pool = setup_sqlite_test().await;
+    let expected = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    // Unnamed expression columns can only be addressed by position
+    let row = sqlx::query("SELECT max(owner), count(*) + 1 FROM accounts")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select account");
+    let owner: Address = row.get(0);
+    let count: i64 = row.get(1);
+    assert_eq!(owner, expected);
+    assert_eq!(count, 2);
+
+    let owner: Address = sqlx::query("SELECT owner, nickname FROM accounts")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select account")
+        .try_get(0)
+        .expect("Failed to decode owner by index");
+    assert_eq!(owner, expected);
+
+    // Out of range indexes are reported, not panicked on
+    let row = sqlx::query("SELECT owner FROM accounts")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select account");
+    assert!(matches!(row.try_get::<Address, _>(1), Err(sqlx::Error::ColumnIndexOutOfBounds { .. })));
+}
+
+#[derive(Debug, FromRow)]
+struct Owner {
+    id: i64,
+    owner: Address,
+}
+
+#[tokio::test]
+async fn test_sqlite_fetch_all_lenient_splits_good_and_bad_rows() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE owners (id INTEGER PRIMARY KEY, owner BLOB NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    // Rows 2 and 4 hold truncated addresses
+    let blobs: [Vec<u8>; 5] = [
+        vec![0x11; 20],
+        vec![0x22; 19],
+        vec![0x33; 20],
+        vec![0x44; 4],
+        vec![0x55; 20],
+    ];
+    for (i, blob) in blobs.iter().enumerate() {
+        sqlx::query("INSERT INTO owners (id, owner) VALUES (?, ?)")
+            .bind(i as i64 + 1)
+            .bind(blob)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert owner");
+    }
+
+    let (owners, errors): (Vec<Owner>, _) = fetch_all_lenient(
+        &pool,
+        sqlx::query("SELECT id, owner FROM owners ORDER BY id"),
+    )
+    .await
+    .expect("Failed to run query");
+
+    let good: Vec<(i64, Address)> = owners.iter().map(|o| (o.id, o.owner)).collect();
+    assert_eq!(
+        good,
+        vec![
+            (1, Address::repeat_byte(0x11)),
+            (3, Address::repeat_byte(0x33)),
+            (5, Address::repeat_byte(0x55)),
+        ]
+    );
+    let bad: Vec<usize> = errors.iter().map(|e| e.index).collect();
+    assert_eq!(bad, vec![1, 3]);
+}
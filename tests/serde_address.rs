@@ -0,0 +1,104 @@
+//! Integration tests for the JSON address representation helpers
+
+use sqlx::{Row, SqlitePool, types::Json};
+use alloy_primitives::{Address, address};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LowerTransfer {
+    #[serde(with = "test_alloy_sqlx::serde_address_lower")]
+    pub from: Address,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChecksumTransfer {
+    #[serde(with = "test_alloy_sqlx::serde_address_checksum")]
+    pub from: Address,
+}
+
+const TEST_ADDR: Address = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+async fn setup_sqlite_test() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query(
+        "CREATE TABLE transfers (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            payload TEXT NOT NULL
+        )"
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create test table");
+
+    pool
+}
+
+async fn store_and_read<T>(pool: &SqlitePool, value: &T) -> (String, T)
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    sqlx::query("DELETE FROM transfers")
+        .execute(pool)
+        .await
+        .expect("Failed to clear table");
+    sqlx::query("INSERT INTO transfers (payload) VALUES (?)")
+        .bind(Json(value))
+        .execute(pool)
+        .await
+        .expect("Failed to insert transfer");
+
+    let row = sqlx::query("SELECT payload, json_extract(payload, '$.from') AS from_text FROM transfers")
+        .fetch_one(pool)
+        .await
+        .expect("Failed to select transfer");
+    let Json(decoded): Json<T> = row.get("payload");
+    (row.get("from_text"), decoded)
+}
+
+#[tokio::test]
+async fn test_sqlite_json_address_lower() {
+    let pool = setup_sqlite_test().await;
+    let transfer = LowerTransfer { from: TEST_ADDR };
+
+    let (from_text, decoded) = store_and_read(&pool, &transfer).await;
+    assert_eq!(from_text, "0x742d35cc6635c0532925a3b8d42cc72b5c2a9a1d");
+    assert_eq!(decoded, transfer);
+}
+
+#[tokio::test]
+async fn test_sqlite_json_address_checksum() {
+    let pool = setup_sqlite_test().await;
+    let transfer = ChecksumTransfer { from: TEST_ADDR };
+
+    let (from_text, decoded) = store_and_read(&pool, &transfer).await;
+    assert_eq!(from_text, TEST_ADDR.to_checksum(None));
+    assert_eq!(decoded, transfer);
+}
+
+#[tokio::test]
+async fn test_sqlite_json_address_checksum_rejects_bad_checksum() {
+    let pool = setup_sqlite_test().await;
+
+    // Valid hex, but flipping the case of every letter breaks the checksum
+    let corrupted: String = TEST_ADDR
+        .to_checksum(None)
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i < 2 { c } else if c.is_ascii_uppercase() { c.to_ascii_lowercase() } else { c.to_ascii_uppercase() })
+        .collect();
+    sqlx::query("INSERT INTO transfers (payload) VALUES (json_object('from', ?))")
+        .bind(&corrupted)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert transfer");
+
+    let row = sqlx::query("SELECT payload FROM transfers")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select transfer");
+    assert!(row.try_get::<Json<ChecksumTransfer>, _>("payload").is_err());
+    assert!(row.try_get::<Json<LowerTransfer>, _>("payload").is_ok());
+}
@@ -0,0 +1,134 @@
+//! Tests for the parameterized SQL fragment builders
+
+use sqlx::SqlitePool;
+use alloy_primitives::{Address, U256, address};
+use test_alloy_sqlx::{Dialect, PrimitiveColumn, SortDir, UnknownSortKey, in_clause_addresses, order_by};
+
+fn three_addresses() -> [Address; 3] {
+    [
+        address!("0x1111111111111111111111111111111111111111"),
+        address!("0x2222222222222222222222222222222222222222"),
+        address!("0x3333333333333333333333333333333333333333"),
+    ]
+}
+
+#[test]
+fn test_in_clause_postgres() {
+    let addresses = three_addresses();
+    let (fragment, binds) = in_clause_addresses(Dialect::Postgres, "address", &addresses);
+
+    assert_eq!(fragment, "address IN ($1, $2, $3)");
+    assert_eq!(binds, addresses);
+}
+
+#[test]
+fn test_in_clause_mysql() {
+    let addresses = three_addresses();
+    let (fragment, binds) = in_clause_addresses(Dialect::MySql, "address", &addresses);
+
+    assert_eq!(fragment, "address IN (?, ?, ?)");
+    assert_eq!(binds, addresses);
+}
+
+#[test]
+fn test_in_clause_sqlite() {
+    let addresses = three_addresses();
+    let (fragment, binds) = in_clause_addresses(Dialect::Sqlite, "w.address", &addresses);
+
+    assert_eq!(fragment, "w.address IN (?, ?, ?)");
+    assert_eq!(binds, addresses);
+}
+
+#[test]
+fn test_in_clause_empty() {
+    let (fragment, binds) = in_clause_addresses(Dialect::Postgres, "address", &[]);
+
+    assert_eq!(fragment, "1 = 0");
+    assert!(binds.is_empty());
+}
+
+#[tokio::test]
+async fn test_sqlite_in_clause_query() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE accounts (address BINARY(20) NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    let addresses = three_addresses();
+    for addr in &addresses {
+        sqlx::query("INSERT INTO accounts (address) VALUES (?)")
+            .bind(addr)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert address");
+    }
+
+    let (fragment, binds) = in_clause_addresses(Dialect::Sqlite, "address", &addresses[..2]);
+    let sql = format!("SELECT address FROM accounts WHERE {} ORDER BY address", fragment);
+    let mut query = sqlx::query_scalar::<_, Address>(&sql);
+    for addr in binds {
+        query = query.bind(addr);
+    }
+    let found = query.fetch_all(&pool).await.expect("Failed to filter addresses");
+
+    assert_eq!(found, addresses[..2]);
+}
+
+#[test]
+fn test_order_by_clauses() {
+    assert_eq!(order_by(PrimitiveColumn::Address, SortDir::Asc), "ORDER BY address ASC");
+    assert_eq!(order_by(PrimitiveColumn::Address, SortDir::Desc), "ORDER BY address DESC");
+    assert_eq!(order_by(PrimitiveColumn::Balance, SortDir::Asc), "ORDER BY balance ASC");
+    assert_eq!(order_by(PrimitiveColumn::Balance, SortDir::Desc), "ORDER BY balance DESC");
+}
+
+#[test]
+fn test_order_by_rejects_unknown_input() {
+    assert_eq!("balance".parse::<PrimitiveColumn>(), Ok(PrimitiveColumn::Balance));
+    assert_eq!("DESC".parse::<SortDir>(), Ok(SortDir::Desc));
+
+    // Anything outside the whitelist never becomes a PrimitiveColumn
+    assert_eq!(
+        "address; DROP TABLE accounts".parse::<PrimitiveColumn>(),
+        Err(UnknownSortKey("address; DROP TABLE accounts".to_string()))
+    );
+    assert!("name".parse::<PrimitiveColumn>().is_err());
+    assert!("sideways".parse::<SortDir>().is_err());
+}
+
+#[tokio::test]
+async fn test_sqlite_order_by_balance() {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+
+    sqlx::query("CREATE TABLE balances (address BINARY(20) NOT NULL, balance BINARY(32) NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    let balances = [U256::from(300), U256::MAX, U256::from(1), U256::from(1) << 200];
+    for (addr, balance) in three_addresses().iter().chain(&[Address::ZERO]).zip(&balances) {
+        sqlx::query("INSERT INTO balances (address, balance) VALUES (?, ?)")
+            .bind(addr)
+            .bind(balance)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert balance");
+    }
+
+    let column: PrimitiveColumn = "balance".parse().expect("valid column");
+    let sql = format!("SELECT balance FROM balances {}", order_by(column, SortDir::Desc));
+    let sorted: Vec<U256> = sqlx::query_scalar(&sql)
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select balances");
+
+    let mut expected = balances.to_vec();
+    expected.sort_by(|a, b| b.cmp(a));
+    assert_eq!(sorted, expected);
+}
@@ -0,0 +1,47 @@
+//! Integration tests running the primitive codecs inside `#[sqlx::test]`
+//!
+//! Fixtures live in `tests/fixtures/` and are applied in the listed order:
+//! `schema` creates the table, `addresses` seeds it with blob literals that
+//! decode through the regular `Address` codec.
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{Address, address};
+
+#[sqlx::test(fixtures("schema", "addresses"))]
+async fn test_fixture_addresses_decode(pool: SqlitePool) {
+    let rows = sqlx::query("SELECT address, name FROM ethereum_addresses ORDER BY address")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select addresses");
+
+    let seeded: Vec<(Address, String)> = rows
+        .iter()
+        .map(|row| (row.get("address"), row.get("name")))
+        .collect();
+    assert_eq!(
+        seeded,
+        vec![
+            (address!("0x00000000219ab540356cBB839Cbe05303d7705Fa"), "Deposit Contract".to_string()),
+            (address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d"), "Test User".to_string()),
+        ]
+    );
+}
+
+#[sqlx::test(fixtures("schema"))]
+async fn test_insert_and_read_address(pool: SqlitePool) {
+    let test_addr = address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d");
+
+    sqlx::query("INSERT INTO ethereum_addresses (address, name) VALUES (?, ?)")
+        .bind(&test_addr)
+        .bind("Inserted")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert address");
+
+    let stored: Address = sqlx::query_scalar("SELECT address FROM ethereum_addresses WHERE name = ?")
+        .bind("Inserted")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+    assert_eq!(stored, test_addr);
+}
@@ -0,0 +1,139 @@
+//! Integration tests for codec tracing
+#![cfg(feature = "trace-codec")]
+
+use std::sync::{Arc, Mutex};
+
+use sqlx::{Row, SqlitePool};
+use alloy_primitives::{U256, address, aliases::U32};
+use test_alloy_sqlx::{AddressBalanceMap, AddressText, PackedU32};
+use tracing::{
+    Event, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+};
+
+/// Collects the message of every event emitted on this thread.
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<String>>>);
+
+struct MessageVisitor<'a>(&'a mut String);
+
+impl Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push_str(&format!("{}={:?} ", field.name(), value));
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut line = String::new();
+        event.record(&mut MessageVisitor(&mut line));
+        self.0.lock().expect("capture lock poisoned").push(line);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[tokio::test]
+async fn test_sqlite_address_text_round_trip_is_traced() {
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+    sqlx::query("CREATE TABLE traced (address TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    sqlx::query("INSERT INTO traced (address) VALUES (?)")
+        .bind(AddressText(address!("0x742d35Cc6635C0532925a3b8D42cC72b5c2A9A1d")))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert address");
+
+    let row = sqlx::query("SELECT address FROM traced")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select address");
+    let _: AddressText = row.get("address");
+
+    let events = capture.0.lock().expect("capture lock poisoned");
+    let codec_events: Vec<&String> = events.iter().filter(|e| e.contains("AddressText")).collect();
+    assert!(
+        codec_events.iter().any(|e| e.contains("encode primitive") && e.contains("len=42")),
+        "no encode event in {codec_events:?}"
+    );
+    assert!(
+        codec_events.iter().any(|e| e.contains("decode primitive") && e.contains("column_type=\"TEXT\"")),
+        "no decode event in {codec_events:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_sqlite_wrapper_codecs_are_traced() {
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .expect("Failed to connect to SQLite");
+    sqlx::query("CREATE TABLE traced (balances TEXT NOT NULL, nonce INTEGER NOT NULL)")
+        .execute(&pool)
+        .await
+        .expect("Failed to create test table");
+
+    let mut balances = AddressBalanceMap::default();
+    balances.0.insert(address!("0xdAC17F958D2ee523a2206206994597C13D831ec7"), U256::from(1000));
+    balances.0.insert(address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"), U256::MAX);
+    sqlx::query("INSERT INTO traced (balances, nonce) VALUES (?, ?)")
+        .bind(&balances)
+        .bind(PackedU32::from(U32::from(7)))
+        .execute(&pool)
+        .await
+        .expect("Failed to insert row");
+
+    let row = sqlx::query("SELECT balances, nonce, length(balances) AS json_len FROM traced")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to select row");
+    let decoded: AddressBalanceMap = row.get("balances");
+    assert_eq!(decoded, balances);
+    let _: PackedU32 = row.get("nonce");
+    let json_len: i64 = row.get("json_len");
+
+    let events = capture.0.lock().expect("capture lock poisoned");
+    let map_events: Vec<&String> = events.iter().filter(|e| e.contains("AddressBalanceMap")).collect();
+    assert!(
+        map_events.iter().any(|e| e.contains("encode primitive") && e.contains(&format!("len={json_len} "))),
+        "no encode event matching the stored JSON length in {map_events:?}"
+    );
+    assert!(
+        map_events.iter().any(|e| e.contains("decode primitive") && e.contains(&format!("len={json_len} "))),
+        "no decode event matching the stored JSON length in {map_events:?}"
+    );
+    let packed_events: Vec<&String> = events.iter().filter(|e| e.contains("PackedUint")).collect();
+    assert!(
+        packed_events.iter().any(|e| e.contains("encode primitive") && e.contains("len=8 ")),
+        "no encode event in {packed_events:?}"
+    );
+    assert!(
+        packed_events.iter().any(|e| e.contains("decode primitive") && e.contains("column_type=\"INTEGER\"")),
+        "no decode event in {packed_events:?}"
+    );
+}
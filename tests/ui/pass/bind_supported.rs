@@ -0,0 +1,18 @@
+use alloy_primitives::{Address, FixedBytes, U256};
+use sqlx::{Row, Sqlite};
+use test_alloy_sqlx::AddressText;
+
+fn main() {
+    let _ = sqlx::query::<Sqlite>("INSERT INTO t VALUES (?, ?, ?, ?)")
+        .bind(Address::ZERO)
+        .bind(FixedBytes::<20>::ZERO)
+        .bind(U256::ZERO)
+        .bind(AddressText(Address::ZERO));
+
+    fn decode(row: &sqlx::sqlite::SqliteRow) {
+        let _: Address = row.get("address");
+        let _: FixedBytes<20> = row.get("key");
+        let _: AddressText = row.get("address_text");
+    }
+    let _ = decode;
+}